@@ -0,0 +1,17 @@
+use console::Term;
+use quizzard::Confirm;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+
+    let sure = Confirm::new("Are you sure?").default(true).ask(&term)?;
+
+    if sure {
+        println!("Let's go!");
+    } else {
+        println!("Maybe next time");
+    }
+
+    Ok(())
+}
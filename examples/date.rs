@@ -0,0 +1,20 @@
+use chrono::Weekday;
+use console::Term;
+use quizzard::Date;
+use std::error::Error;
+
+// The `Date` input requires the "date" feature of quizzard to be enabled
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+    term.hide_cursor()?;
+
+    let date = Date::new("When should we release?")
+        .disabled_weekdays([Weekday::Sat, Weekday::Sun])
+        .ask(&term)?;
+
+    println!("Releasing on {date}");
+
+    term.show_cursor()?;
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use console::Term;
+use quizzard::DurationInput;
+use std::error::Error;
+use std::time;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+
+    let timeout = DurationInput::new("How long until the request times out?")
+        .min(time::Duration::from_secs(1))
+        .max(time::Duration::from_secs(60 * 60))
+        .ask(&term)?;
+
+    println!("Timing out after {timeout:?}");
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use console::Term;
+use quizzard::{DynamicMultiSelect, DynamicSelect};
+use std::error::Error;
+use std::fs;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+    term.hide_cursor()?;
+
+    let files: Vec<_> = fs::read_dir(".")?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .collect();
+
+    let file = DynamicSelect::new("Which file?", files.clone(), |path| {
+        path.display().to_string()
+    })
+    .ask(&term)?;
+    println!("You picked {}", file.display());
+
+    let chosen = DynamicMultiSelect::new("Which files to delete?", files, |path| {
+        path.display().to_string()
+    })
+    .max(2)
+    .ask(&term)?;
+    println!("You would delete {chosen:?}");
+
+    term.show_cursor()?;
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use console::Term;
+use quizzard::Editor;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+
+    let message = Editor::new("Write a commit message")
+        .template("\n# Lines starting with '#' will be ignored")
+        .validator(|text| {
+            if text.is_empty() {
+                Err("The commit message can't be empty".into())
+            } else {
+                Ok(text)
+            }
+        })
+        .ask(&term)?;
+
+    println!("Committing with message:\n{message}");
+
+    Ok(())
+}
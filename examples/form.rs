@@ -0,0 +1,68 @@
+use console::Term;
+use quizzard::{Form, SelectEnum};
+use std::error::Error;
+
+#[derive(SelectEnum, Debug)]
+enum Template {
+    Binary,
+    Library,
+    Workspace,
+}
+
+#[derive(SelectEnum, Debug)]
+enum License {
+    #[prompt("MIT")]
+    Mit,
+    #[prompt("Apache 2.0")]
+    Apache,
+    #[prompt("GPL 3.0")]
+    Gpl,
+}
+
+#[derive(Form)]
+struct Project {
+    #[prompt("What is the project called?", validator = project_name)]
+    name: String,
+    #[prompt("Describe the project")]
+    description: Option<String>,
+    #[prompt("Which template should be used?", default = Template::Library)]
+    template: Template,
+    #[prompt("Which licenses should it use?", min = 1)]
+    licenses: Vec<License>,
+    #[prompt("How many workers should CI use?", default = 2, min = 1, max = 16)]
+    workers: u8,
+    #[prompt("Create a git repository?", default = true)]
+    git: bool,
+}
+
+fn project_name(input: String) -> Result<String, String> {
+    if input.is_empty() {
+        return Err("You must enter a name".to_string());
+    }
+    if !input
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("Names can only contain letters, numbers, '-' and '_'".to_string());
+    }
+    Ok(input)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+
+    let project = Project::ask(&term)?;
+    println!(
+        "Creating {} ({}) from the {:?} template under {:?} with {} workers",
+        project.name,
+        project.description.unwrap_or_default(),
+        project.template,
+        project.licenses,
+        project.workers,
+    );
+    if project.git {
+        println!("Initializing a git repository");
+    }
+
+    Ok(())
+}
@@ -18,7 +18,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     let job = Input::new("What's your job title?")
         .default("Developer")
-        .validator(|input| Ok(input))
+        .validator(Ok)
         .ask(&term)?;
 
     println!("Your name is {name} and your job title is {job}");
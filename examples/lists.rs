@@ -0,0 +1,30 @@
+use console::Term;
+use quizzard::ListInput;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+
+    let tags = ListInput::new("Which tags should be added?")
+        .max(5)
+        .validator(|input| {
+            if input.contains(' ') {
+                return Err("Tags can't contain spaces".to_string());
+            }
+            Ok(input.to_lowercase())
+        })
+        .ask(&term)?;
+
+    let ports = ListInput::new("Which ports should be opened?")
+        .min(1)
+        .validator(|input| {
+            input
+                .parse::<u16>()
+                .map_err(|_| "Must be a port between 0 and 65535".to_string())
+        })
+        .ask(&term)?;
+
+    println!("Tagged with {tags:?} and opening {ports:?}");
+
+    Ok(())
+}
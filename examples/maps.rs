@@ -0,0 +1,30 @@
+use console::Term;
+use quizzard::MapInput;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+
+    let env = MapInput::new("Which environment variables should be set?")
+        .key_validator(|key| {
+            if key.is_empty() || !key.chars().all(|c| c.is_ascii_uppercase() || c == '_') {
+                return Err("Keys must be uppercase letters and underscores".to_string());
+            }
+            Ok(key)
+        })
+        .value_validator(Ok)
+        .ask(&term)?;
+
+    let limits = MapInput::new("What limits should be applied?")
+        .key_validator(Ok)
+        .value_validator(|value| {
+            value
+                .parse::<u32>()
+                .map_err(|_| "Limits must be a whole number".to_string())
+        })
+        .ask(&term)?;
+
+    println!("Setting {env:?} with limits {limits:?}");
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use console::Term;
+use quizzard::{Matrix, SelectEnum};
+use std::error::Error;
+
+#[derive(SelectEnum, Debug)]
+enum Statement {
+    #[prompt("The docs are helpful")]
+    Docs,
+    #[prompt("The API is easy to use")]
+    Api,
+    #[prompt("The output looks nice")]
+    Output,
+}
+
+#[derive(SelectEnum, Debug)]
+enum Agreement {
+    #[prompt("Strongly disagree")]
+    StronglyDisagree,
+    Disagree,
+    Neutral,
+    Agree,
+    #[prompt("Strongly agree")]
+    StronglyAgree,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+    term.hide_cursor()?;
+
+    let answers = Matrix::<Statement, Agreement>::new("How do you feel about quizzard?")
+        .required(true)
+        .ask(&term)?;
+    for (n, answer) in answers.iter().enumerate() {
+        match answer {
+            Some(answer) => println!("{}: {answer:?}", Statement::prompt_at(n)),
+            None => println!("{}: no answer", Statement::prompt_at(n)),
+        }
+    }
+
+    term.show_cursor()?;
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use console::Term;
+use quizzard::{Hostname, IpAddress, Port, SocketAddress, UrlInput};
+use std::error::Error;
+use std::net::{IpAddr, Ipv4Addr};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+
+    let url = UrlInput::new("Where is the API hosted?")
+        .schemes(["http", "https"])
+        .ask(&term)?;
+    let host = Hostname::new("What is the database host?").ask(&term)?;
+    let port = Port::new("Which port is the database on?")
+        .privileged(false)
+        .ask(&term)?;
+    let gateway = IpAddress::<Ipv4Addr>::new("What is the gateway?").ask(&term)?;
+    let dns = IpAddress::<IpAddr>::new("What is the DNS server?").ask(&term)?;
+    let bind = SocketAddress::new("Which address should we bind to?").ask(&term)?;
+
+    println!("API {url}, database {host}:{port}, gateway {gateway}, DNS {dns}, binding {bind}");
+
+    Ok(())
+}
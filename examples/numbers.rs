@@ -1,5 +1,5 @@
 use console::Term;
-use quizzard::Integer;
+use quizzard::{Float, Integer};
 use std::error::Error;
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -7,7 +7,13 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     let age = Integer::<u8>::new("What's your age?").max(120).ask(&term)?;
 
-    println!("You're {age} years old");
+    let height = Float::<f32>::new("How tall are you in metres?")
+        .min(0.3)
+        .max(3.0)
+        .fraction_digits(2)
+        .ask(&term)?;
+
+    println!("You're {age} years old and {height}m tall");
 
     Ok(())
 }
@@ -6,7 +6,9 @@ fn main() -> Result<(), Box<dyn Error>> {
     let term = Term::stdout();
     term.hide_cursor()?;
 
-    let answer = Select::<Food>::new("Which of these is your favourite?").ask(&term)?;
+    let answer = Select::<Food>::new("Which of these is your favourite?")
+        .filter(true)
+        .ask(&term)?;
     println!("You answered Food::{answer:?}");
 
     term.show_cursor()?;
@@ -0,0 +1,23 @@
+use console::Term;
+use quizzard::Password;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+
+    let pin = Password::new("What's your PIN?").hidden(true).ask(&term)?;
+
+    let password = Password::new("Choose a password")
+        .confirmation("Repeat the password")
+        .reveal(true)
+        .strength(true)
+        .ask(&term)?;
+
+    println!(
+        "Your PIN is {} digits and your password is {} characters",
+        pin.len(),
+        password.len()
+    );
+
+    Ok(())
+}
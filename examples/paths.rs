@@ -0,0 +1,25 @@
+use console::Term;
+use quizzard::PathInput;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+
+    let manifest = PathInput::new("Where is your manifest?")
+        .must_exist(true)
+        .extensions(["toml"])
+        .ask(&term)?;
+
+    let output = PathInput::new("Where should the output go?")
+        .default("~/")
+        .directories_only(true)
+        .ask(&term)?;
+
+    println!(
+        "Reading {} and writing to {}",
+        manifest.display(),
+        output.display()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use console::Term;
+use quizzard::{Rank, SelectEnum};
+use std::error::Error;
+
+#[derive(SelectEnum, Debug)]
+enum Feature {
+    Speed,
+    Stability,
+    Documentation,
+    #[prompt("More Examples")]
+    Examples,
+    #[prompt("Windows Support")]
+    Windows,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+    term.hide_cursor()?;
+
+    let ranking = Rank::<Feature>::new("What should we focus on?")
+        .top(3)
+        .ask(&term)?;
+    println!("Your top priorities are {ranking:?}");
+
+    term.show_cursor()?;
+    Ok(())
+}
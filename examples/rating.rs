@@ -0,0 +1,20 @@
+use console::Term;
+use quizzard::Rating;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+    term.hide_cursor()?;
+
+    let stars = Rating::new("How was your stay?")
+        .stars(true)
+        .labels("Awful", "Amazing")
+        .ask(&term)?;
+
+    let score = Rating::nps("How likely are you to recommend us?").ask_opt(&term)?;
+
+    println!("You gave us {stars} stars and a score of {score:?}");
+
+    term.show_cursor()?;
+    Ok(())
+}
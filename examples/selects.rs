@@ -6,7 +6,9 @@ use std::error::Error;
 enum Speed {
     Slow,
     Medium,
+    #[prompt("Fast", description = "Uses more memory")]
     Fast,
+    /// Only runs on a good day
     #[prompt("Really Fast")]
     ReallyFast,
     #[prompt("EXTREMELY FAST!!!")]
@@ -15,6 +17,7 @@ enum Speed {
 
 #[derive(SelectEnum, Debug)]
 enum Language {
+    /// Blazingly fast
     Rust,
     Go,
     C,
@@ -22,6 +25,8 @@ enum Language {
     Cpp,
     #[prompt("JavaScript 🤮")]
     JavaScript,
+    #[prompt(disabled = "Not installed")]
+    Zig,
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -31,7 +36,9 @@ fn main() -> Result<(), Box<dyn Error>> {
     let answer = Select::<Speed>::new("How fast is your code?").ask(&term)?;
     println!("You answered Speed::{answer:?}");
 
-    let answer = Select::<Speed>::new("How fast is your code? (optional)").ask_opt(&term)?;
+    let answer = Select::<Speed>::new("How fast is your code? (optional)")
+        .disabled(|speed| matches!(speed, Speed::Slow).then(|| "Never slow".to_string()))
+        .ask_opt(&term)?;
     println!("You optionally answered {answer:?}");
 
     let answers = MultiSelect::<Language>::new("What languages to you use?")
@@ -0,0 +1,24 @@
+use console::Term;
+use quizzard::Slider;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+    term.hide_cursor()?;
+
+    let volume = Slider::<u8>::new("How loud?")
+        .max(100)
+        .initial(50)
+        .step(5)
+        .ask(&term)?;
+
+    let threads = Slider::<u8>::new("How many threads?")
+        .min(1)
+        .max(16)
+        .ask(&term)?;
+
+    println!("Setting the volume to {volume} and using {threads} threads");
+
+    term.show_cursor()?;
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use console::Term;
+use quizzard::Input;
+use std::error::Error;
+
+const LANGUAGES: [&str; 8] = [
+    "C",
+    "C++",
+    "C#",
+    "Go",
+    "Java",
+    "JavaScript",
+    "Python",
+    "Rust",
+];
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+
+    let language = Input::new("What is your favourite language?")
+        .suggester(|input: &str, _cursor: usize| {
+            LANGUAGES
+                .iter()
+                .filter(|l| {
+                    !input.is_empty() && l.to_lowercase().starts_with(&input.to_lowercase())
+                })
+                .map(|l| l.to_string())
+                .collect()
+        })
+        .validator(Ok)
+        .ask(&term)?;
+
+    println!("You like {language}");
+
+    Ok(())
+}
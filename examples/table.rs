@@ -0,0 +1,52 @@
+use console::Term;
+use quizzard::TableSelect;
+use std::error::Error;
+
+struct Machine {
+    name: &'static str,
+    region: &'static str,
+    cpus: u32,
+    memory: u32,
+    description: &'static str,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+
+    let machines = vec![
+        Machine {
+            name: "web-1",
+            region: "eu-west",
+            cpus: 4,
+            memory: 16,
+            description: "Serves the public website and the marketing pages",
+        },
+        Machine {
+            name: "db-1",
+            region: "eu-west",
+            cpus: 16,
+            memory: 128,
+            description: "Primary database holding every customer record we have",
+        },
+        Machine {
+            name: "worker-1",
+            region: "us-east",
+            cpus: 8,
+            memory: 32,
+            description: "Runs background jobs such as sending emails and building reports",
+        },
+    ];
+
+    let machine = TableSelect::new("Which machine should be restarted?", machines)
+        .column("Name", |m| m.name.to_string())
+        .column("Region", |m| m.region.to_string())
+        .column("CPUs", |m| m.cpus.to_string())
+        .column("Memory", |m| format!("{} GB", m.memory))
+        .column("Description", |m| m.description.to_string())
+        .sort_by_key(|m| std::cmp::Reverse(m.cpus))
+        .ask(&term)?;
+
+    println!("Restarting {}", machine.name);
+
+    Ok(())
+}
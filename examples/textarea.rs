@@ -0,0 +1,22 @@
+use console::Term;
+use quizzard::TextArea;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+
+    let address = TextArea::new("What's your address?")
+        .max_lines(4)
+        .validator(|text| {
+            if text.lines().count() < 2 {
+                Err("An address needs at least two lines".into())
+            } else {
+                Ok(text)
+            }
+        })
+        .ask(&term)?;
+
+    println!("Sending it to\n{address}");
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use console::Term;
+use quizzard::{TreeMultiSelect, TreeNode, TreeSelect};
+use std::error::Error;
+
+fn workspace() -> Vec<TreeNode<&'static str>> {
+    vec![
+        TreeNode::new("quizzard", "quizzard").children([
+            TreeNode::new("lib", "quizzard/lib"),
+            TreeNode::new("examples", "quizzard/examples").children([
+                TreeNode::new("input", "quizzard/examples/input"),
+                TreeNode::new("selects", "quizzard/examples/selects"),
+                TreeNode::new("trees", "quizzard/examples/trees"),
+            ]),
+        ]),
+        TreeNode::new("quizzard_derive", "quizzard_derive")
+            .child(TreeNode::new("lib", "quizzard_derive/lib")),
+    ]
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+
+    let target = TreeSelect::new("Which target should be run?", workspace()).ask(&term)?;
+
+    let targets = TreeMultiSelect::new("Which targets should be built?", workspace())
+        .expanded(true)
+        .min(1)
+        .ask(&term)?;
+
+    println!("Running {target} and building {targets:?}");
+
+    Ok(())
+}
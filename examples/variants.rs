@@ -0,0 +1,36 @@
+use console::Term;
+use quizzard::{Select, SelectEnum};
+use std::error::Error;
+
+#[derive(SelectEnum)]
+enum Auth {
+    #[prompt("No authentication")]
+    None,
+    #[prompt("API token")]
+    Token(#[prompt("What is the token?")] String),
+    #[prompt("Username and password")]
+    Basic {
+        #[prompt("What is the username?")]
+        user: String,
+        #[prompt("What is the password?", password = true)]
+        pass: String,
+    },
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let term = Term::stdout();
+
+    let auth = Select::<Auth>::new("How should we authenticate?").ask(&term)?;
+    match auth {
+        Auth::None => println!("Connecting anonymously"),
+        Auth::Token(token) => println!("Connecting with a {} character token", token.len()),
+        Auth::Basic { user, pass } => {
+            println!(
+                "Connecting as {user} with a {} character password",
+                pass.len()
+            )
+        }
+    }
+
+    Ok(())
+}
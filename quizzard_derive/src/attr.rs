@@ -0,0 +1,92 @@
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Expr, Ident, LitStr, Token};
+
+/// The arguments of a `#[prompt("Title", key = value)]` attribute
+#[derive(Default)]
+pub struct PromptArgs {
+    pub title: Option<LitStr>,
+    pub options: Vec<PromptOption>,
+}
+
+pub struct PromptOption {
+    pub key: Ident,
+    pub value: Expr,
+}
+
+impl Parse for PromptArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut title = None;
+        if input.peek(LitStr) {
+            title = Some(input.parse()?);
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        let mut options: Vec<PromptOption> = Vec::new();
+        for option in Punctuated::<PromptOption, Token![,]>::parse_terminated(input)? {
+            if options.iter().any(|o| o.key == option.key) {
+                return Err(syn::Error::new(
+                    option.key.span(),
+                    format!("duplicate `{}` key", option.key),
+                ));
+            }
+            options.push(option);
+        }
+        Ok(Self { title, options })
+    }
+}
+
+impl Parse for PromptOption {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if !input.peek(Ident) {
+            return Err(input.error("expected a title string or a `key = value` option"));
+        }
+        let key = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+        Ok(Self { key, value })
+    }
+}
+
+impl PromptArgs {
+    /// Parse the `#[prompt]` attribute if there is one
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Option<Self>> {
+        let mut prompts = attrs.iter().filter(|a| a.path().is_ident("prompt"));
+        let Some(attr) = prompts.next() else {
+            return Ok(None);
+        };
+        if let Some(duplicate) = prompts.next() {
+            return Err(syn::Error::new_spanned(
+                duplicate,
+                "duplicate `#[prompt]` attribute",
+            ));
+        }
+        attr.parse_args().map(Some)
+    }
+
+    /// Error on the first option with a key not in the allowed list
+    pub fn check_keys(&self, allowed: &[&str]) -> syn::Result<()> {
+        for option in &self.options {
+            if !allowed.iter().any(|key| option.key == key) {
+                let msg = if allowed.is_empty() {
+                    format!("unknown key `{}`, no keys are supported here", option.key)
+                } else {
+                    format!(
+                        "unknown key `{}`, expected one of: {}",
+                        option.key,
+                        allowed.join(", ")
+                    )
+                };
+                return Err(syn::Error::new(option.key.span(), msg));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the value of an option
+    pub fn get(&self, key: &str) -> Option<&Expr> {
+        self.options.iter().find(|o| o.key == key).map(|o| &o.value)
+    }
+}
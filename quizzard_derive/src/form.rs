@@ -0,0 +1,282 @@
+use crate::attr::PromptArgs;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Expr, ExprLit, Fields, GenericArgument, Lit, PathArguments, Type};
+
+const INTEGERS: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+/// The question used to ask for a type
+enum Kind<'a> {
+    Text,
+    Integer(&'a Type),
+    Bool,
+    Select(&'a Type),
+    MultiSelect(&'a Type),
+}
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "Form can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "Form can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut names = Vec::new();
+    let mut questions = Vec::new();
+    for field in fields {
+        let name = field.ident.as_ref().expect("Named fields have an ident");
+        let args = PromptArgs::from_attrs(&field.attrs)?.unwrap_or_default();
+        let title = match &args.title {
+            Some(title) => title.value(),
+            None => humanize(&name.to_string()),
+        };
+        names.push(name);
+        questions.push(question(&field.ty, &title, &args)?);
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::quizzard::Form for #name #ty_generics #where_clause {
+            fn ask(term: &::quizzard::console::Term) -> ::std::result::Result<Self, ::quizzard::Error> {
+                Ok(Self {
+                    #(#names: #questions),*
+                })
+            }
+        }
+    })
+}
+
+/// Builds the expression asking the question for a field
+pub fn question(ty: &Type, title: &str, args: &PromptArgs) -> syn::Result<TokenStream> {
+    let (optional, ty) = match generic_arg(ty, "Option") {
+        Some(inner) => (true, inner),
+        None => (false, ty),
+    };
+    let kind = kind(ty);
+
+    if password(args)? {
+        if !matches!(kind, Kind::Text) {
+            return Err(syn::Error::new(
+                ty.span(),
+                "a password can only be asked for String fields",
+            ));
+        }
+        args.check_keys(&["password"])?;
+        let ask = quote!(::quizzard::Password::new(#title).ask(term)?);
+        return Ok(if optional {
+            quote!({
+                let password = #ask;
+                (!password.is_empty()).then_some(password)
+            })
+        } else {
+            ask
+        });
+    }
+
+    if let Some(validator) = args.get("validator") {
+        if !matches!(kind, Kind::Text | Kind::Integer(_)) {
+            return Err(syn::Error::new(
+                validator.span(),
+                "a validator can only be used on String and integer fields",
+            ));
+        }
+        args.check_keys(&["default", "validator"])?;
+        let default = args.get("default").map(|d| quote!(.default(#d)));
+        let validator = if optional {
+            quote! {
+                |input: ::std::string::String| {
+                    if input.is_empty() {
+                        return Ok(None);
+                    }
+                    (#validator)(input).map(Some)
+                }
+            }
+        } else {
+            quote!(#validator)
+        };
+        return Ok(quote_spanned! {ty.span()=>
+            ::quizzard::Input::new(#title)
+                #default
+                .validator(#validator)
+                .ask(term)?
+        });
+    }
+
+    let question = match kind {
+        Kind::Text => {
+            args.check_keys(&["default", "validator", "password"])?;
+            let default = args.get("default").map(|d| quote!(.default(#d)));
+            if optional {
+                quote! {
+                    ::quizzard::Input::new(#title)
+                        #default
+                        .validator(|input: ::std::string::String| Ok((!input.is_empty()).then_some(input)))
+                        .display(|ans| ans.clone().unwrap_or_else(|| "Skipped".to_string()))
+                        .ask(term)?
+                }
+            } else {
+                quote! {
+                    ::quizzard::Input::new(#title)
+                        #default
+                        .validator(Ok)
+                        .ask(term)?
+                }
+            }
+        }
+        Kind::Integer(ty) => {
+            args.check_keys(&["default", "min", "max", "validator"])?;
+            let options = options(
+                args,
+                &[("default", "default"), ("min", "min"), ("max", "max")],
+            );
+            let ask = if optional {
+                quote!(ask_opt)
+            } else {
+                quote!(ask)
+            };
+            quote! {
+                ::quizzard::Integer::<#ty>::new(#title)
+                    #(#options)*
+                    .#ask(term)?
+            }
+        }
+        Kind::Bool => {
+            args.check_keys(&["default"])?;
+            let options = options(args, &[("default", "default")]);
+            let ask = if optional {
+                quote!(ask_opt)
+            } else {
+                quote!(ask)
+            };
+            quote! {
+                ::quizzard::Confirm::new(#title)
+                    #(#options)*
+                    .#ask(term)?
+            }
+        }
+        Kind::Select(ty) => {
+            args.check_keys(&["default"])?;
+            let options = options(args, &[("default", "initial")]);
+            let ask = if optional {
+                quote!(ask_opt)
+            } else {
+                quote!(ask)
+            };
+            quote_spanned! {ty.span()=>
+                ::quizzard::Select::<#ty>::new(#title)
+                    #(#options)*
+                    .#ask(term)?
+            }
+        }
+        Kind::MultiSelect(ty) => {
+            args.check_keys(&["default", "min", "max"])?;
+            let options = options(
+                args,
+                &[("default", "initial"), ("min", "min"), ("max", "max")],
+            );
+            let ask = quote_spanned! {ty.span()=>
+                ::quizzard::MultiSelect::<#ty>::new(#title)
+                    #(#options)*
+                    .ask(term)?
+            };
+            if optional {
+                quote!({
+                    let answers = #ask;
+                    (!answers.is_empty()).then_some(answers)
+                })
+            } else {
+                ask
+            }
+        }
+    };
+    Ok(question)
+}
+
+/// Whether the field is a password, which has to be set with a `bool` literal
+fn password(args: &PromptArgs) -> syn::Result<bool> {
+    match args.get("password") {
+        None => Ok(false),
+        Some(Expr::Lit(ExprLit {
+            lit: Lit::Bool(password),
+            ..
+        })) => Ok(password.value),
+        Some(other) => Err(syn::Error::new_spanned(other, "expected `true` or `false`")),
+    }
+}
+
+/// The builder calls for the options set in the attribute, mapping each key to its method
+fn options(args: &PromptArgs, methods: &[(&str, &str)]) -> Vec<TokenStream> {
+    methods
+        .iter()
+        .filter_map(|(key, method)| {
+            let value = args.get(key)?;
+            let method = syn::Ident::new(method, value.span());
+            Some(quote!(.#method(#value)))
+        })
+        .collect()
+}
+
+fn kind(ty: &Type) -> Kind<'_> {
+    if let Some(inner) = generic_arg(ty, "Vec") {
+        return Kind::MultiSelect(inner);
+    }
+    match last_ident(ty).as_deref() {
+        Some("String") => Kind::Text,
+        Some("bool") => Kind::Bool,
+        Some(ident) if INTEGERS.contains(&ident) => Kind::Integer(ty),
+        _ => Kind::Select(ty),
+    }
+}
+
+fn last_ident(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => {
+            path.path.segments.last().map(|s| s.ident.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// The type argument of a type such as `Option<T>` if the type has the given name
+fn generic_arg<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+/// Turns a field name such as `first_name` into a title such as `First name`
+pub fn humanize(name: &str) -> String {
+    let name = name.trim_start_matches("r#").replace('_', " ");
+    let mut chars = name.trim().chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,233 @@
+use crate::attr::PromptArgs;
+use crate::form;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Attribute, Data, DataEnum, DeriveInput, Expr, ExprLit, Fields, Lit, Meta, MetaNameValue,
+    Variant,
+};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "SelectEnum can only be derived for enums",
+            ))
+        }
+    };
+    if data.variants.is_empty() {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "SelectEnum can't be derived for enums without variants",
+        ));
+    }
+
+    let variants = prompts(data)?;
+    let prompts: Vec<&String> = variants.iter().map(|v| &v.prompt).collect();
+    let descriptions = optional_strs(
+        quote!(DESCRIPTIONS),
+        variants.iter().map(|v| v.description.as_ref()),
+    );
+    let disabled = optional_strs(
+        quote!(DISABLED),
+        variants.iter().map(|v| v.disabled.as_ref()),
+    );
+    let patterns = patterns(data);
+    let indices = 0..patterns.len();
+
+    // Only variants without fields can be created without asking the user
+    let (unit_indices, unit_variants): (Vec<usize>, Vec<_>) = data
+        .variants
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| v.fields.is_empty())
+        .map(|(n, v)| (n, &v.ident))
+        .unzip();
+
+    // Every variant can only be listed when none of them have fields
+    let all_units = data.variants.iter().all(|v| v.fields.is_empty());
+    let variants_const = all_units.then(|| {
+        quote! {
+            const VARIANTS: &'static [Self] = &[
+                #(Self::#unit_variants),*
+            ];
+        }
+    });
+    let ask_variant = if all_units {
+        None
+    } else {
+        let arms = ask_variant_arms(data)?;
+        Some(quote! {
+            fn ask_variant(
+                n: usize,
+                term: &::quizzard::console::Term,
+            ) -> ::std::result::Result<Option<Self>, ::quizzard::Error> {
+                Ok(match n {
+                    #(#arms,)*
+                    _ => None,
+                })
+            }
+        })
+    };
+
+    let name = &input.ident;
+    Ok(quote! {
+        impl ::quizzard::SelectEnum for #name {
+            #variants_const
+            const PROMPTS: &'static [&'static str] = &[
+                #(#prompts),*
+            ];
+            #descriptions
+            #disabled
+            fn prompt(&self) -> &'static str {
+                match self {
+                    #(#patterns => #prompts),*
+                }
+            }
+            fn to_index(&self) -> usize {
+                match self {
+                    #(#patterns => #indices),*
+                }
+            }
+            fn from_index(n: usize) -> Option<Self> {
+                match n {
+                    #(#unit_indices => Some(Self::#unit_variants),)*
+                    _ => None
+                }
+            }
+            #ask_variant
+        }
+    })
+}
+
+/// The prompt shown for a variant along with its description and the reason it's disabled
+struct VariantPrompt<'a> {
+    variant: &'a Variant,
+    prompt: String,
+    description: Option<TokenStream>,
+    disabled: Option<TokenStream>,
+}
+
+/// The prompt of each variant, erroring if two variants share a prompt
+fn prompts(data: &DataEnum) -> syn::Result<Vec<VariantPrompt<'_>>> {
+    let mut prompts: Vec<VariantPrompt> = Vec::new();
+    for variant in &data.variants {
+        let args = PromptArgs::from_attrs(&variant.attrs)?;
+        let (prompt, span) = match args.as_ref().and_then(|args| args.title.as_ref()) {
+            Some(title) => (title.value(), title.span()),
+            None if args.as_ref().is_some_and(|args| args.options.is_empty()) => {
+                return Err(syn::Error::new(
+                    variant.ident.span(),
+                    "expected a prompt such as `#[prompt(\"Prompt\")]`",
+                ))
+            }
+            None => (variant.ident.to_string(), variant.ident.span()),
+        };
+        let args = args.unwrap_or_default();
+        args.check_keys(&["description", "disabled"])?;
+        let description = match args.get("description") {
+            Some(description) => Some(quote!(#description)),
+            None => doc_comment(&variant.attrs).map(|doc| quote!(#doc)),
+        };
+        let disabled = args.get("disabled").map(|reason| quote!(#reason));
+
+        if let Some(other) = prompts.iter().find(|p| p.prompt == prompt) {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "duplicate prompt `{prompt}`, also used by `{}`",
+                    other.variant.ident
+                ),
+            ));
+        }
+        prompts.push(VariantPrompt {
+            variant,
+            prompt,
+            description,
+            disabled,
+        });
+    }
+    Ok(prompts)
+}
+
+/// Set an optional string for each variant, leaving the empty default when none are set
+fn optional_strs<'a>(
+    name: TokenStream,
+    values: impl Iterator<Item = Option<&'a TokenStream>> + Clone,
+) -> Option<TokenStream> {
+    values.clone().any(|v| v.is_some()).then(|| {
+        let values = values.map(|v| match v {
+            Some(v) => quote!(Some(#v)),
+            None => quote!(None),
+        });
+        quote! {
+            const #name: &'static [Option<&'static str>] = &[
+                #(#values),*
+            ];
+        }
+    })
+}
+
+/// The doc comment of a variant joined into a single line
+fn doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|a| a.path().is_ident("doc"))
+        .filter_map(|a| match &a.meta {
+            Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(doc), ..
+                    }),
+                ..
+            }) => Some(doc.value().trim().to_string()),
+            _ => None,
+        })
+        .filter(|line| !line.is_empty())
+        .collect();
+    (!lines.is_empty()).then(|| lines.join(" "))
+}
+
+/// The patterns matching each variant while ignoring any fields
+fn patterns(data: &DataEnum) -> Vec<TokenStream> {
+    data.variants
+        .iter()
+        .map(|v| {
+            let name = &v.ident;
+            match &v.fields {
+                Fields::Named(_) => quote!(Self::#name { .. }),
+                Fields::Unnamed(_) => quote!(Self::#name(..)),
+                Fields::Unit => quote!(Self::#name),
+            }
+        })
+        .collect()
+}
+
+/// The match arms creating each variant, asking a question for each of its fields
+fn ask_variant_arms(data: &DataEnum) -> syn::Result<Vec<TokenStream>> {
+    let mut arms = Vec::new();
+    for (n, variant) in data.variants.iter().enumerate() {
+        let name = &variant.ident;
+        let mut questions = Vec::new();
+        for (i, field) in variant.fields.iter().enumerate() {
+            let args = PromptArgs::from_attrs(&field.attrs)?.unwrap_or_default();
+            let title = match (&args.title, &field.ident) {
+                (Some(title), _) => title.value(),
+                (None, Some(ident)) => form::humanize(&ident.to_string()),
+                (None, None) if variant.fields.len() == 1 => form::humanize(&name.to_string()),
+                (None, None) => format!("{} {}", form::humanize(&name.to_string()), i + 1),
+            };
+            questions.push(form::question(&field.ty, &title, &args)?);
+        }
+
+        let fields = variant.fields.iter().map(|f| &f.ident);
+        arms.push(match &variant.fields {
+            Fields::Named(_) => quote!(#n => Some(Self::#name { #(#fields: #questions),* })),
+            Fields::Unnamed(_) => quote!(#n => Some(Self::#name(#(#questions),*))),
+            Fields::Unit => quote!(#n => Some(Self::#name)),
+        });
+    }
+    Ok(arms)
+}
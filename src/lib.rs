@@ -1,3 +1,6 @@
+// Derived code refers to console through quizzard so users don't need to depend on it
+#[doc(hidden)]
+pub use console;
 pub use questions::*;
 pub use quizzard_derive::*;
 use thiserror::Error;
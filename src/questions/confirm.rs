@@ -0,0 +1,119 @@
+use crate::questions::{formatted_answered_question, formatted_question, ARROW};
+use crate::Error::Other;
+use crate::Result;
+use console::{style, Key, Term};
+
+/// Get a yes or no answer from the user
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::Confirm;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let answer = Confirm::new("Are you sure?")
+///     .default(false)
+///     .ask(&term)?;
+/// println!("You answered {answer}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct Confirm {
+    title: String,
+    default: Option<bool>,
+}
+
+impl Confirm {
+    /// Creates a confirm with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            default: None,
+        }
+    }
+
+    /// Set the answer used when enter is pressed without choosing
+    pub fn default(mut self, value: bool) -> Self {
+        self.default = Some(value);
+        self
+    }
+
+    /// Ask the question getting the answer as a result
+    pub fn ask(&self, term: &Term) -> Result<bool> {
+        self.run(term, false)?.ok_or(Other("No answer was given"))
+    }
+
+    /// Ask the question optionally getting either the answer or none as a result,
+    /// pressing enter without choosing skips the question
+    pub fn ask_opt(&self, term: &Term) -> Result<Option<bool>> {
+        self.run(term, true)
+    }
+
+    fn run(&self, term: &Term, optional: bool) -> Result<Option<bool>> {
+        let hint = match self.default {
+            Some(true) => "(Y/n)",
+            Some(false) => "(y/N)",
+            None => "(y/n)",
+        };
+        term.write_line(&formatted_question(
+            format!("{} {}", self.title, hint),
+            &[
+                ("y/n", "answer"),
+                ("enter", if optional { "confirm/skip" } else { "confirm" }),
+            ],
+        ))?;
+
+        let mut selected = self.default;
+
+        loop {
+            term.write_str(&Self::formatted_options(selected))?;
+
+            loop {
+                let key = term.read_key()?;
+                let rerender = match key {
+                    Key::Char('y' | 'Y') => return self.answer(term, Some(true)),
+                    Key::Char('n' | 'N') => return self.answer(term, Some(false)),
+                    Key::Enter if selected.is_some() || optional => {
+                        return self.answer(term, selected)
+                    }
+                    Key::ArrowLeft | Key::ArrowRight | Key::Tab => {
+                        selected = Some(!selected.unwrap_or(false));
+                        true
+                    }
+                    _ => false,
+                };
+
+                if rerender {
+                    term.clear_line()?;
+                    break;
+                }
+            }
+        }
+    }
+
+    fn answer(&self, term: &Term, ans: Option<bool>) -> Result<Option<bool>> {
+        term.clear_line()?;
+        term.clear_last_lines(1)?;
+        term.write_line(&formatted_answered_question(
+            self.title.clone(),
+            String::from(match ans {
+                Some(true) => "Yes",
+                Some(false) => "No",
+                None => "Skipped",
+            }),
+        ))?;
+        Ok(ans)
+    }
+
+    fn formatted_options(selected: Option<bool>) -> String {
+        let option = |value: bool, prompt: &str| {
+            if selected == Some(value) {
+                format!("{} {}", style(ARROW).red(), style(prompt).red().bold())
+            } else {
+                format!("  {prompt}")
+            }
+        };
+        format!("{}  {}", option(true, "Yes"), option(false, "No"))
+    }
+}
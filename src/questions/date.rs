@@ -0,0 +1,335 @@
+#![cfg(feature = "date")]
+
+use crate::questions::{formatted_answered_question, formatted_question, ARROW};
+use crate::Error::Other;
+use crate::Result;
+use chrono::{Datelike, Days, Local, Months, NaiveDate, Weekday};
+use console::{style, Key, Term};
+use std::result;
+
+const FORMAT: &str = "%Y-%m-%d";
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+// Month header, weekday header, six weeks and the typed input
+const LINES: usize = 9;
+
+/// Get a date input from the user using a calendar
+///
+/// The arrow keys move between days and weeks while page up and page down move between
+/// months, a date can also be typed as `YYYY-MM-DD`
+///
+/// # Example
+/// ```no_run
+/// use chrono::{NaiveDate, Weekday};
+/// use console::Term;
+/// use quizzard::Date;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let date = Date::new("When should we release?")
+///     .min(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+///     .disabled_weekdays([Weekday::Sat, Weekday::Sun])
+///     .ask(&term)?;
+/// println!("Releasing on {date}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct Date {
+    title: String,
+    initial: Option<NaiveDate>,
+    min: NaiveDate,
+    max: NaiveDate,
+    disabled_weekdays: Vec<Weekday>,
+}
+
+impl Date {
+    /// Creates a date input with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            initial: None,
+            min: NaiveDate::MIN,
+            max: NaiveDate::MAX,
+            disabled_weekdays: Vec::new(),
+        }
+    }
+
+    /// Set the initially selected date, defaults to today
+    pub fn initial(mut self, initial: NaiveDate) -> Self {
+        self.initial = Some(initial);
+        self
+    }
+
+    /// Sets the earliest date accepted by the input
+    pub fn min(mut self, min: NaiveDate) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the latest date accepted by the input
+    pub fn max(mut self, max: NaiveDate) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the days of the week which can't be selected
+    pub fn disabled_weekdays(mut self, weekdays: impl IntoIterator<Item = Weekday>) -> Self {
+        self.disabled_weekdays = weekdays.into_iter().collect();
+        self
+    }
+
+    /// Ask the question getting the selected date as a result
+    pub fn ask(&self, term: &Term) -> Result<NaiveDate> {
+        if self.min > self.max {
+            return Err(Other("The min date is after the max date"));
+        }
+        term.write_line(&formatted_question(
+            self.title.clone(),
+            &[("arrows", "move"), ("enter", "select")],
+        ))?;
+
+        let initial = self.initial.unwrap_or_else(|| Local::now().date_naive());
+        let initial = initial.clamp(self.min, self.max);
+        let mut selected = [true, false]
+            .into_iter()
+            .filter_map(|forward| self.skip_disabled(initial, forward))
+            .find(|date| *date >= self.min && *date <= self.max)
+            .unwrap_or(initial);
+        let mut input = String::new();
+        let mut active_err_msg = false;
+
+        loop {
+            self.write_calendar(term, selected, &input)?;
+
+            loop {
+                let key = term.read_key()?;
+                let rerender = match key {
+                    Key::ArrowLeft => {
+                        self.move_to(&mut selected, false, |d| d.checked_sub_days(Days::new(1)))
+                    }
+                    Key::ArrowRight => {
+                        self.move_to(&mut selected, true, |d| d.checked_add_days(Days::new(1)))
+                    }
+                    Key::ArrowUp => {
+                        self.move_to(&mut selected, false, |d| d.checked_sub_days(Days::new(7)))
+                    }
+                    Key::ArrowDown => {
+                        self.move_to(&mut selected, true, |d| d.checked_add_days(Days::new(7)))
+                    }
+                    Key::PageUp => self.move_to(&mut selected, false, |d| {
+                        d.checked_sub_months(Months::new(1))
+                    }),
+                    Key::PageDown => self.move_to(&mut selected, true, |d| {
+                        d.checked_add_months(Months::new(1))
+                    }),
+                    Key::Char(c) if c.is_ascii_digit() || c == '-' => {
+                        input.push(c);
+                        // A typed date is shown even when disabled so the error explains why
+                        if let Ok(date) = NaiveDate::parse_from_str(&input, FORMAT) {
+                            if date >= self.min && date <= self.max {
+                                selected = date;
+                            }
+                        }
+                        true
+                    }
+                    Key::Backspace if !input.is_empty() => {
+                        input.pop();
+                        true
+                    }
+                    Key::Enter => {
+                        let validated = if input.is_empty() {
+                            self.validator(selected)
+                        } else {
+                            NaiveDate::parse_from_str(&input, FORMAT)
+                                .map_err(|_| {
+                                    "Invalid date, must be written as YYYY-MM-DD".to_string()
+                                })
+                                .and_then(|date| self.validator(date))
+                        };
+                        match validated {
+                            Ok(ans) => {
+                                term.clear_last_lines(LINES + if !active_err_msg { 1 } else { 2 })?;
+                                term.write_line(&formatted_answered_question(
+                                    self.title.clone(),
+                                    ans.format(FORMAT).to_string(),
+                                ))?;
+                                return Ok(ans);
+                            }
+                            Err(msg) => {
+                                term.clear_last_lines(LINES)?;
+                                if active_err_msg {
+                                    term.clear_last_lines(1)?;
+                                }
+                                term.write_line(&format!(
+                                    "{} {}",
+                                    style('X').red(),
+                                    style(msg).red()
+                                ))?;
+                                active_err_msg = true;
+                                input.clear();
+                                break;
+                            }
+                        }
+                    }
+                    _ => false,
+                };
+
+                if rerender {
+                    term.clear_last_lines(LINES)?;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Move the selected date, continuing in the same direction past disabled weekdays
+    fn move_to(
+        &self,
+        selected: &mut NaiveDate,
+        forward: bool,
+        to: impl FnOnce(NaiveDate) -> Option<NaiveDate>,
+    ) -> bool {
+        match to(*selected).and_then(|date| self.skip_disabled(date, forward)) {
+            Some(date) if date >= self.min && date <= self.max => {
+                *selected = date;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The first date on or after the date, or before it when going backwards, which isn't on
+    /// a disabled weekday
+    fn skip_disabled(&self, mut date: NaiveDate, forward: bool) -> Option<NaiveDate> {
+        for _ in 0..WEEKDAYS.len() {
+            if !self.disabled_weekdays.contains(&date.weekday()) {
+                return Some(date);
+            }
+            date = if forward {
+                date.succ_opt()?
+            } else {
+                date.pred_opt()?
+            };
+        }
+        None
+    }
+
+    fn validator(&self, date: NaiveDate) -> result::Result<NaiveDate, String> {
+        if date < self.min {
+            return Err(format!(
+                "Too early! Must be on or after {}",
+                self.min.format(FORMAT)
+            ));
+        }
+        if date > self.max {
+            return Err(format!(
+                "Too late! Must be on or before {}",
+                self.max.format(FORMAT)
+            ));
+        }
+        if self.disabled_weekdays.contains(&date.weekday()) {
+            return Err(format!("{}s can't be selected", date.format("%A")));
+        }
+        Ok(date)
+    }
+
+    fn write_calendar(&self, term: &Term, selected: NaiveDate, input: &str) -> Result<()> {
+        term.write_line(&format!(
+            "{}",
+            style(format!("{:^20}", selected.format("%B %Y"))).bold()
+        ))?;
+        term.write_line(
+            &WEEKDAYS
+                .iter()
+                .map(|weekday| {
+                    let name = &weekday.to_string()[..2];
+                    if self.disabled_weekdays.contains(weekday) {
+                        style(name).dim().to_string()
+                    } else {
+                        name.to_string()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+        )?;
+
+        let first = selected.with_day(1).unwrap_or(selected);
+        let offset = first.weekday().num_days_from_monday() as usize;
+        for week in 0..6 {
+            let mut cells = Vec::with_capacity(7);
+            for weekday in 0..7 {
+                let day = (week * 7 + weekday) as u32;
+                let date = (day as usize)
+                    .checked_sub(offset)
+                    .and_then(|n| first.checked_add_days(Days::new(n as u64)))
+                    .filter(|date| date.month() == first.month());
+                let Some(date) = date else {
+                    cells.push("  ".to_string());
+                    continue;
+                };
+                let cell = format!("{:>2}", date.day());
+                cells.push(if date == selected {
+                    style(cell).red().bold().reverse().to_string()
+                } else if self.validator(date).is_err() {
+                    style(cell).dim().to_string()
+                } else {
+                    cell
+                });
+            }
+            term.write_line(cells.join(" ").trim_end())?;
+        }
+
+        term.write_line(&format!(
+            "{} {input}",
+            style(ARROW.to_string().repeat(2)).red()
+        ))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        // 2024-01-01 is a Monday
+        NaiveDate::from_ymd_opt(2024, 1, day).unwrap()
+    }
+
+    #[test]
+    fn skip_disabled_weekdays() {
+        let picker = Date::new("").disabled_weekdays([Weekday::Sat, Weekday::Sun]);
+        assert_eq!(picker.skip_disabled(date(5), true), Some(date(5)));
+        assert_eq!(picker.skip_disabled(date(6), true), Some(date(8)));
+        assert_eq!(picker.skip_disabled(date(7), false), Some(date(5)));
+        assert_eq!(
+            Date::new("")
+                .disabled_weekdays(WEEKDAYS)
+                .skip_disabled(date(1), true),
+            None
+        );
+    }
+
+    #[test]
+    fn arrows_skip_disabled_weekdays() {
+        let picker = Date::new("").disabled_weekdays([Weekday::Sat, Weekday::Sun]);
+        let mut selected = date(5);
+        assert!(picker.move_to(&mut selected, true, |d| d.succ_opt()));
+        assert_eq!(selected, date(8));
+        assert!(picker.move_to(&mut selected, false, |d| d.pred_opt()));
+        assert_eq!(selected, date(5));
+    }
+
+    #[test]
+    fn min_after_max_is_an_error() {
+        let picker = Date::new("").min(date(2)).max(date(1));
+        assert!(picker.ask(&Term::stdout()).is_err());
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{Input, Result};
+use console::Term;
+use std::result;
+use std::time;
+
+const UNITS: [(&[&str], u64); 6] = [
+    (&["w", "week", "weeks"], 7 * 24 * 60 * 60 * 1000),
+    (&["d", "day", "days"], 24 * 60 * 60 * 1000),
+    (&["h", "hr", "hrs", "hour", "hours"], 60 * 60 * 1000),
+    (&["m", "min", "mins", "minute", "minutes"], 60 * 1000),
+    (&["s", "sec", "secs", "second", "seconds"], 1000),
+    (&["ms", "millis", "millisecond", "milliseconds"], 1),
+];
+
+/// Get a duration input from the user such as `1h30m`, `90s` or `2d`
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::DurationInput;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let timeout = DurationInput::new("How long until the request times out?")
+///     .max(std::time::Duration::from_secs(60 * 60))
+///     .ask(&term)?;
+/// println!("Timing out after {timeout:?}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct DurationInput {
+    title: String,
+    min: time::Duration,
+    max: time::Duration,
+}
+
+impl DurationInput {
+    /// Creates a duration input with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            min: time::Duration::ZERO,
+            max: time::Duration::MAX,
+        }
+    }
+
+    /// Sets the min duration accepted by the input
+    pub fn min(mut self, min: time::Duration) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the max duration accepted by the input
+    pub fn max(mut self, max: time::Duration) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Ask the question getting the inputted duration as a result
+    pub fn ask(&self, term: &Term) -> Result<time::Duration> {
+        let min = self.min;
+        let max = self.max;
+        Input::new(self.title.clone())
+            .validator(move |input| Self::validator(input, min, max))
+            .display(|ans| format_duration(*ans))
+            .ask(term)
+    }
+
+    fn validator(
+        input: String,
+        min: time::Duration,
+        max: time::Duration,
+    ) -> result::Result<time::Duration, String> {
+        let ans = parse_duration(&input)?;
+        if ans < min {
+            return Err(format!(
+                "Too short! Must be at least {}",
+                format_duration(min)
+            ));
+        }
+        if ans > max {
+            return Err(format!(
+                "Too long! Must be at most {}",
+                format_duration(max)
+            ));
+        }
+        Ok(ans)
+    }
+}
+
+fn parse_duration(input: &str) -> result::Result<time::Duration, String> {
+    let input = input.trim().to_lowercase();
+    if input.is_empty() {
+        return Err("You must enter a duration".to_string());
+    }
+
+    let mut millis: u64 = 0;
+    let mut chars = input.chars().peekable();
+    while chars.peek().is_some() {
+        let mut number = String::new();
+        while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+            number.push(c);
+        }
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let mut unit = String::new();
+        while let Some(c) = chars.next_if(|c| c.is_alphabetic()) {
+            unit.push(c);
+        }
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+        if number.is_empty() {
+            return Err(match unit.is_empty() {
+                true => "An invalid character is present".to_string(),
+                false => format!("Expected a number before '{unit}'"),
+            });
+        }
+        if unit.is_empty() {
+            return Err(format!(
+                "Missing a unit after {number}, such as 30s, 5m or 1h"
+            ));
+        }
+        let Some((_, scale)) = UNITS
+            .iter()
+            .find(|(names, _)| names.contains(&unit.as_str()))
+        else {
+            return Err(format!("Unknown unit '{unit}'"));
+        };
+
+        millis = number
+            .parse::<u64>()
+            .ok()
+            .and_then(|n| n.checked_mul(*scale))
+            .and_then(|n| n.checked_add(millis))
+            .ok_or("Too long! The duration is too large".to_string())?;
+    }
+
+    Ok(time::Duration::from_millis(millis))
+}
+
+fn format_duration(duration: time::Duration) -> String {
+    let mut millis = duration.as_millis();
+    let mut parts = Vec::new();
+    for (names, scale) in UNITS {
+        let scale = scale as u128;
+        if millis >= scale {
+            parts.push(format!("{}{}", millis / scale, names[0]));
+            millis %= scale;
+        }
+    }
+    if parts.is_empty() {
+        return "0s".to_string();
+    }
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_durations() {
+        let cases = [
+            ("90s", 90_000),
+            ("1h30m", 90 * 60 * 1000),
+            ("1h 30m", 90 * 60 * 1000),
+            ("2 days", 2 * 24 * 60 * 60 * 1000),
+            ("1W", 7 * 24 * 60 * 60 * 1000),
+            ("  250ms ", 250),
+            ("1m1m", 2 * 60 * 1000),
+            ("0s", 0),
+        ];
+        for (input, millis) in cases {
+            assert_eq!(
+                parse_duration(input),
+                Ok(time::Duration::from_millis(millis)),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_durations() {
+        let cases = [
+            ("", "You must enter a duration"),
+            ("  ", "You must enter a duration"),
+            ("-5s", "An invalid character is present"),
+            ("h", "Expected a number before 'h'"),
+            ("30", "Missing a unit after 30, such as 30s, 5m or 1h"),
+            ("3 fortnights", "Unknown unit 'fortnights'"),
+            (
+                "99999999999999999999w",
+                "Too long! The duration is too large",
+            ),
+        ];
+        for (input, err) in cases {
+            assert_eq!(parse_duration(input), Err(err.to_string()), "{input}");
+        }
+    }
+
+    #[test]
+    fn formats_durations() {
+        let cases = [
+            (0, "0s"),
+            (250, "250ms"),
+            (90_000, "1m 30s"),
+            (90 * 60 * 1000, "1h 30m"),
+            (8 * 24 * 60 * 60 * 1000 + 1, "1w 1d 1ms"),
+        ];
+        for (millis, formatted) in cases {
+            assert_eq!(
+                format_duration(time::Duration::from_millis(millis)),
+                formatted
+            );
+        }
+    }
+}
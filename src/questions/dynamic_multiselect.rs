@@ -0,0 +1,186 @@
+use crate::questions::dynamic_select::{dynamic_option_list, DescriptionFunc, DisplayFunc};
+use crate::questions::select::DisabledFunc;
+use crate::questions::{
+    formatted_answered_question, formatted_min_max_error, formatted_question, formatted_selected,
+};
+use crate::Error::Other;
+use crate::Result;
+use console::{Key, Term};
+
+/// Get multiple items from a list only known at runtime from the user
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::DynamicMultiSelect;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let databases = vec!["users", "orders", "logs"];
+/// let answers = DynamicMultiSelect::new("Which databases to back up?", databases, |d| {
+///     d.to_string()
+/// })
+/// .min(1)
+/// .ask(&term)?;
+/// println!("Backing up {answers:?}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct DynamicMultiSelect<'a, T> {
+    title: String,
+    items: Vec<T>,
+    display: Box<DisplayFunc<T>>,
+    initial: Vec<usize>,
+    min: usize,
+    max: usize,
+    filter: bool,
+    description: Option<Box<DescriptionFunc<'a, T>>>,
+    disabled: Option<Box<DisabledFunc<'a, T>>>,
+}
+
+impl<'a, T> DynamicMultiSelect<'a, T> {
+    /// Creates a select with the given title, items and function for displaying an item
+    pub fn new(
+        title: impl Into<String>,
+        items: impl IntoIterator<Item = T>,
+        display: impl Fn(&T) -> String + 'static,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            items: items.into_iter().collect(),
+            display: Box::new(display),
+            initial: Vec::new(),
+            min: 0,
+            max: usize::MAX,
+            filter: false,
+            description: None,
+            disabled: None,
+        }
+    }
+
+    /// Set the indices of the initially selected items
+    pub fn initial(mut self, initial: Vec<usize>) -> Self {
+        self.initial = initial;
+        self
+    }
+
+    /// Set the minimum items allowed to be selected
+    pub fn min(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the maximum items allowed to be selected
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set whether typing filters the items, replacing the number key shortcuts
+    pub fn filter(mut self, filter: bool) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Set the function giving the description shown beside the highlighted item
+    pub fn description(mut self, description: impl Fn(&T) -> Option<String> + 'a) -> Self {
+        self.description = Some(Box::new(description));
+        self
+    }
+
+    /// Disable items, the function returns the reason an item is disabled or none if it
+    /// can be selected
+    pub fn disabled(mut self, disabled: impl Fn(&T) -> Option<String> + 'a) -> Self {
+        self.disabled = Some(Box::new(disabled));
+        self
+    }
+
+    /// Ask the question getting a list of the selected items as a result
+    pub fn ask(self, term: &Term) -> Result<Vec<T>> {
+        let indices = self.ask_indices(term)?;
+        let mut items: Vec<Option<T>> = self.items.into_iter().map(Some).collect();
+        Ok(indices.iter().filter_map(|n| items[*n].take()).collect())
+    }
+
+    /// Ask the question getting a list of the indices of the selected items as a result
+    pub fn ask_indices(&self, term: &Term) -> Result<Vec<usize>> {
+        if self.items.is_empty() {
+            return Err(Other("There are no items to select from"));
+        }
+        let mut list = dynamic_option_list(
+            &self.items,
+            self.display.as_ref(),
+            self.description.as_deref(),
+            self.disabled.as_deref(),
+            self.filter,
+        );
+        if list.enabled_len() == 0 {
+            return Err(Other("All items are disabled"));
+        }
+        if list.enabled_len() < self.min {
+            return Err(Other("Fewer items are enabled than the minimum"));
+        }
+        let mut selected: Vec<usize> = self
+            .initial
+            .iter()
+            .copied()
+            .filter(|n| *n < self.items.len())
+            .collect();
+        if selected.iter().any(|n| list.is_disabled(*n)) {
+            return Err(Other("An initial item is disabled"));
+        }
+        let mut active_err_msg = false;
+        term.write_line(&formatted_question(
+            self.title.clone(),
+            &[("space", "select"), ("enter", "proceed")],
+        ))?;
+        loop {
+            list.write(term, Some(&selected))?;
+            let rendered = list.rendered_lines(term);
+
+            loop {
+                let key = term.read_key()?;
+                let rerender = match key {
+                    Key::Char(' ') => {
+                        let Some(index) = list.highlighted() else {
+                            continue;
+                        };
+                        if let Some(n) = selected.iter().position(|v| *v == index) {
+                            selected.remove(n);
+                        } else {
+                            selected.push(index);
+                        }
+                        true
+                    }
+                    Key::Enter => {
+                        if selected.len() < self.min || selected.len() > self.max {
+                            term.clear_last_lines(rendered)?;
+                            if active_err_msg {
+                                term.clear_last_lines(1)?;
+                            }
+                            term.write_line(&formatted_min_max_error(
+                                selected.len(),
+                                self.min,
+                                self.max,
+                            ))?;
+                            active_err_msg = true;
+                            break;
+                        }
+                        term.clear_last_lines(rendered + if !active_err_msg { 1 } else { 2 })?;
+                        term.write_line(&formatted_answered_question(
+                            self.title.clone(),
+                            formatted_selected(&list, &selected),
+                        ))?;
+                        return Ok(selected);
+                    }
+                    key => list.handle_key(&key),
+                };
+
+                if rerender {
+                    term.clear_last_lines(rendered)?;
+                    break;
+                }
+            }
+        }
+    }
+}
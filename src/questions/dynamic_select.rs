@@ -0,0 +1,153 @@
+use crate::questions::select::DisabledFunc;
+use crate::questions::OptionList;
+use crate::Error::Other;
+use crate::Result;
+use console::Term;
+
+pub(super) type DisplayFunc<T> = dyn Fn(&T) -> String;
+pub(super) type DescriptionFunc<'a, T> = dyn Fn(&T) -> Option<String> + 'a;
+
+/// Get a single item from a list only known at runtime from the user
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::DynamicSelect;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let branches = vec!["main".to_string(), "develop".to_string()];
+/// let branch = DynamicSelect::new("Which branch?", branches, |b| b.clone()).ask(&term)?;
+/// println!("Checking out {branch}");
+///
+/// // Items can be filtered, described and disabled in the same way as a `Select`,
+/// // the functions can borrow from their surroundings
+/// let protected = "main";
+/// let branches = vec!["main".to_string(), "develop".to_string()];
+/// let branch = DynamicSelect::new("Which branch?", branches, |b| b.clone())
+///     .filter(true)
+///     .disabled(|b| (b == protected).then(|| "Protected".to_string()))
+///     .ask(&term)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct DynamicSelect<'a, T> {
+    title: String,
+    items: Vec<T>,
+    display: Box<DisplayFunc<T>>,
+    initial: Option<usize>,
+    filter: bool,
+    description: Option<Box<DescriptionFunc<'a, T>>>,
+    disabled: Option<Box<DisabledFunc<'a, T>>>,
+}
+
+impl<'a, T> DynamicSelect<'a, T> {
+    /// Creates a select with the given title, items and function for displaying an item
+    pub fn new(
+        title: impl Into<String>,
+        items: impl IntoIterator<Item = T>,
+        display: impl Fn(&T) -> String + 'static,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            items: items.into_iter().collect(),
+            display: Box::new(display),
+            initial: None,
+            filter: false,
+            description: None,
+            disabled: None,
+        }
+    }
+
+    /// Set the index of the initially selected item
+    pub fn initial(mut self, initial: usize) -> Self {
+        self.initial = Some(initial);
+        self
+    }
+
+    /// Set whether typing filters the items, replacing the number key shortcuts
+    pub fn filter(mut self, filter: bool) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Set the function giving the description shown beside the highlighted item
+    pub fn description(mut self, description: impl Fn(&T) -> Option<String> + 'a) -> Self {
+        self.description = Some(Box::new(description));
+        self
+    }
+
+    /// Disable items, the function returns the reason an item is disabled or none if it
+    /// can be selected
+    pub fn disabled(mut self, disabled: impl Fn(&T) -> Option<String> + 'a) -> Self {
+        self.disabled = Some(Box::new(disabled));
+        self
+    }
+
+    /// Ask the question getting the selected item as a result
+    pub fn ask(mut self, term: &Term) -> Result<T> {
+        let index = self.ask_index(term)?;
+        Ok(self.items.swap_remove(index))
+    }
+
+    /// Ask the question getting the index of the selected item as a result
+    pub fn ask_index(&self, term: &Term) -> Result<usize> {
+        self.run(term, false)?.ok_or(Other("No item was selected"))
+    }
+
+    /// Ask the question optionally getting either the selected item or none as a result
+    pub fn ask_opt(mut self, term: &Term) -> Result<Option<T>> {
+        let index = self.ask_index_opt(term)?;
+        Ok(index.map(|n| self.items.swap_remove(n)))
+    }
+
+    /// Ask the question optionally getting either the index of the selected item or none as a result
+    pub fn ask_index_opt(&self, term: &Term) -> Result<Option<usize>> {
+        self.run(term, true)
+    }
+
+    fn run(&self, term: &Term, optional: bool) -> Result<Option<usize>> {
+        if self.items.is_empty() {
+            return Err(Other("There are no items to select from"));
+        }
+        let mut list = dynamic_option_list(
+            &self.items,
+            self.display.as_ref(),
+            self.description.as_deref(),
+            self.disabled.as_deref(),
+            self.filter,
+        );
+        if list.enabled_len() == 0 {
+            return Err(Other("All items are disabled"));
+        }
+        if let Some(initial) = self.initial.filter(|n| *n < self.items.len()) {
+            if list.is_disabled(initial) {
+                return Err(Other("The initial item is disabled"));
+            }
+            list.set_cursor(initial);
+        }
+        list.select(term, &self.title, optional)
+    }
+}
+
+/// The options of a select over items only known at runtime
+pub(super) fn dynamic_option_list<T>(
+    items: &[T],
+    display: &DisplayFunc<T>,
+    description: Option<&DescriptionFunc<'_, T>>,
+    disabled: Option<&DisabledFunc<'_, T>>,
+    filter: bool,
+) -> OptionList {
+    OptionList::new(
+        items.iter().map(display).collect(),
+        items
+            .iter()
+            .map(|item| description.and_then(|f| f(item)))
+            .collect(),
+        items
+            .iter()
+            .map(|item| disabled.and_then(|f| f(item)))
+            .collect(),
+        filter,
+    )
+}
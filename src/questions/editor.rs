@@ -0,0 +1,213 @@
+use crate::questions::{formatted_answered_question, formatted_question, multiline_summary};
+use crate::Error::Other;
+use crate::{NoValidator, Result, Validator};
+use console::{style, Key, Term};
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::marker::PhantomData;
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::result;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Get long-form text from the user by opening their `$VISUAL` or `$EDITOR`
+///
+/// Lines starting with `#` are removed from the text before it's validated
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::Editor;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let notes = Editor::new("Write the release notes")
+///     .template("\n# Lines starting with '#' will be ignored")
+///     .extension("md")
+///     .validator(|text| {
+///         if text.trim().is_empty() {
+///             Err("The release notes can't be empty".into())
+///         } else {
+///             Ok(text)
+///         }
+///     })
+///     .ask(&term)?;
+/// println!("{notes}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct Editor<T, Validator = NoValidator> {
+    title: String,
+    template: Option<String>,
+    extension: String,
+    strip_comments: bool,
+    validator: Validator,
+    data: PhantomData<T>,
+}
+
+impl<T> Editor<T, NoValidator> {
+    /// Creates an editor with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            template: None,
+            extension: String::from("txt"),
+            strip_comments: true,
+            validator: NoValidator,
+            data: PhantomData::<T>,
+        }
+    }
+
+    /// Set the function for validating and returning the parsed type
+    pub fn validator(
+        self,
+        value: impl Fn(String) -> result::Result<T, String> + 'static,
+    ) -> Editor<T, Validator<T>> {
+        let validator = Validator::<T> {
+            method: Box::new(value),
+        };
+        Editor::<T, Validator<T>> {
+            title: self.title,
+            template: self.template,
+            extension: self.extension,
+            strip_comments: self.strip_comments,
+            validator,
+            data: Default::default(),
+        }
+    }
+}
+
+impl<T> Editor<T, Validator<T>> {
+    /// Ask the question getting the validated type as a result
+    pub fn ask(&self, term: &Term) -> Result<T> {
+        term.write_line(&formatted_question(
+            self.title.clone(),
+            &[("enter", "open editor")],
+        ))?;
+
+        let mut contents = self.template.clone().unwrap_or_default();
+        let mut active_err_msg = false;
+
+        loop {
+            while term.read_key()? != Key::Enter {}
+
+            let path = create_temp_file(&self.extension, &contents)?;
+            let edited = open_editor(&path).and_then(|_| Ok(fs::read_to_string(&path)?));
+            let _ = fs::remove_file(&path);
+            contents = edited?;
+
+            let text = if self.strip_comments {
+                strip_comments(&contents)
+            } else {
+                contents.clone()
+            };
+
+            match (self.validator.method)(text.clone()) {
+                Ok(ans) => {
+                    term.clear_last_lines(if !active_err_msg { 1 } else { 2 })?;
+                    term.write_line(&formatted_answered_question(
+                        self.title.clone(),
+                        multiline_summary(&text),
+                    ))?;
+                    return Ok(ans);
+                }
+                Err(msg) => {
+                    if active_err_msg {
+                        term.clear_last_lines(1)?;
+                    }
+                    term.write_line(&format!("{} {}", style('X').red(), style(msg).red()))?;
+                    active_err_msg = true;
+                }
+            }
+        }
+    }
+}
+
+impl<T, Validator> Editor<T, Validator> {
+    /// Set the text the editor is opened with
+    pub fn template(mut self, value: impl Into<String>) -> Self {
+        self.template = Some(value.into());
+        self
+    }
+
+    /// Set the extension of the edited file, allowing editors to pick a syntax
+    pub fn extension(mut self, value: impl Into<String>) -> Self {
+        self.extension = value.into().trim_start_matches('.').to_string();
+        self
+    }
+
+    /// Set whether lines starting with `#` are removed, defaults to true
+    pub fn strip_comments(mut self, value: bool) -> Self {
+        self.strip_comments = value;
+        self
+    }
+}
+
+fn open_editor(path: &Path) -> Result<()> {
+    let editor = env::var("VISUAL")
+        .ok()
+        .filter(|e| !e.trim().is_empty())
+        .or_else(|| env::var("EDITOR").ok().filter(|e| !e.trim().is_empty()))
+        .unwrap_or_else(|| String::from(if cfg!(windows) { "notepad" } else { "vi" }));
+
+    let mut args = editor.split_whitespace();
+    let program = args.next().ok_or(Other("No editor is set"))?;
+    let status = Command::new(program).args(args).arg(path).status()?;
+    if !status.success() {
+        return Err(Other("The editor exited with an error"));
+    }
+    Ok(())
+}
+
+/// Create a new file in the temp dir only the current user can read, failing rather than
+/// following a file or symlink someone else has already put in its place
+fn create_temp_file(extension: &str, contents: &str) -> io::Result<PathBuf> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+
+    let mut attempts = 0;
+    loop {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or_default();
+        let path = env::temp_dir().join(format!(
+            "quizzard-{}-{nanos}.{extension}",
+            std::process::id()
+        ));
+        match options.open(&path) {
+            Ok(mut file) => {
+                file.write_all(contents.as_bytes())?;
+                return Ok(path);
+            }
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists && attempts < 100 => {
+                attempts += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+fn strip_comments(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().filter(|line| !line.starts_with('#')).collect();
+    lines.join("\n").trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_comments_only_removes_lines_starting_with_a_hash() {
+        let text = "# Title\nSome text\n  # indented\n    #!/bin/sh\n#comment";
+        assert_eq!(
+            strip_comments(text),
+            "Some text\n  # indented\n    #!/bin/sh"
+        );
+    }
+}
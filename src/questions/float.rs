@@ -0,0 +1,288 @@
+use crate::{Input, Result};
+use console::Term;
+use std::fmt::Display;
+use std::result;
+
+/// Get a floating point number input from the user
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::Float;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let answer = Float::<f64>::new("What's the price?")
+///     .min(0.0)
+///     .fraction_digits(2)
+///     .ask(&term)?;
+/// println!("That'll be ${answer}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct Float<T: FloatValue> {
+    title: String,
+    min: T,
+    max: T,
+    fraction_digits: Option<usize>,
+    scientific: bool,
+}
+
+impl<T: FloatValue> Float<T> {
+    /// Creates a float input with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            min: T::MIN,
+            max: T::MAX,
+            fraction_digits: None,
+            scientific: false,
+        }
+    }
+
+    /// Sets the min value accepted by the input
+    pub fn min(mut self, min: T) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the max value accepted by the input
+    pub fn max(mut self, max: T) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the max number of digits accepted after the decimal point
+    pub fn fraction_digits(mut self, fraction_digits: usize) -> Self {
+        self.fraction_digits = Some(fraction_digits);
+        self
+    }
+
+    /// Set whether scientific notation such as `1.5e3` is accepted
+    pub fn scientific(mut self, scientific: bool) -> Self {
+        self.scientific = scientific;
+        self
+    }
+
+    /// Ask the question getting the inputted number as a result
+    pub fn ask(&self, term: &Term) -> Result<T> {
+        let min = self.min;
+        let max = self.max;
+        let fraction_digits = self.fraction_digits;
+        Input::new(self.title.clone())
+            .charset(self.charset())
+            .validator(move |input| Self::validator(input, min, max, fraction_digits))
+            .ask(term)
+    }
+
+    fn validator(
+        input: String,
+        min: T,
+        max: T,
+        fraction_digits: Option<usize>,
+    ) -> result::Result<T, String> {
+        if input.is_empty() {
+            return Err("You must enter a value".to_string());
+        }
+
+        if let Some(fraction_digits) = fraction_digits {
+            let mantissa = input.split(['e', 'E']).next().unwrap_or_default();
+            let digits = mantissa.split_once('.').map(|(_, f)| f.len()).unwrap_or(0);
+            if digits > fraction_digits {
+                return Err(format!(
+                    "Too many decimal places! Must have {} or less",
+                    fraction_digits
+                ));
+            }
+        }
+
+        match T::parse(&input) {
+            Some(ans) if !ans.is_finite() => Err("Must be a finite number".to_string()),
+            Some(ans) => {
+                if ans < min {
+                    return Err(format!("Too small! Must be above or equal to {}", min));
+                }
+                if ans > max {
+                    return Err(format!("Too big! Must be below or equal to {}", max));
+                }
+                Ok(ans)
+            }
+            None => Err("Unable to convert input to number".to_string()),
+        }
+    }
+
+    fn charset(&self) -> Vec<char> {
+        let mut charset: Vec<char> = ('0'..='9').collect();
+        charset.push('.');
+        if self.scientific {
+            charset.extend(['e', 'E', '+', '-']);
+        } else if self.min < T::ZERO {
+            charset.push('-');
+        }
+        charset
+    }
+}
+
+pub trait FloatValue: PartialOrd + Display + Copy + 'static {
+    const MIN: Self;
+    const MAX: Self;
+    const ZERO: Self;
+    fn parse(input: &str) -> Option<Self>;
+
+    /// Whether the value is neither infinite nor NaN
+    fn is_finite(&self) -> bool {
+        true
+    }
+}
+
+impl FloatValue for f32 {
+    const MIN: Self = f32::MIN;
+    const MAX: Self = f32::MAX;
+    const ZERO: Self = 0.0;
+
+    fn parse(input: &str) -> Option<Self> {
+        input.parse().ok()
+    }
+
+    fn is_finite(&self) -> bool {
+        f32::is_finite(*self)
+    }
+}
+
+impl FloatValue for f64 {
+    const MIN: Self = f64::MIN;
+    const MAX: Self = f64::MAX;
+    const ZERO: Self = 0.0;
+
+    fn parse(input: &str) -> Option<Self> {
+        input.parse().ok()
+    }
+
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl FloatValue for rust_decimal::Decimal {
+    const MIN: Self = rust_decimal::Decimal::MIN;
+    const MAX: Self = rust_decimal::Decimal::MAX;
+    const ZERO: Self = rust_decimal::Decimal::ZERO;
+
+    fn parse(input: &str) -> Option<Self> {
+        if input.contains(['e', 'E']) {
+            rust_decimal::Decimal::from_scientific(input).ok()
+        } else {
+            input.parse().ok()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(input: &str, fraction_digits: Option<usize>) -> result::Result<f64, String> {
+        Float::<f64>::validator(input.to_string(), -10.0, 10.0, fraction_digits)
+    }
+
+    #[test]
+    fn parses_numbers() {
+        let cases = [
+            ("1", 1.0),
+            ("-2.5", -2.5),
+            (".5", 0.5),
+            ("1e1", 10.0),
+            ("-1E-2", -0.01),
+        ];
+        for (input, value) in cases {
+            assert_eq!(validate(input, None), Ok(value), "{input}");
+        }
+    }
+
+    #[test]
+    fn limits_fraction_digits() {
+        let cases = [
+            ("1.25", Ok(1.25)),
+            ("1.250", Err("Too many decimal places! Must have 2 or less")),
+            ("1.255", Err("Too many decimal places! Must have 2 or less")),
+            ("1.5e-1", Ok(0.15)),
+            (
+                "1.555e1",
+                Err("Too many decimal places! Must have 2 or less"),
+            ),
+            ("3", Ok(3.0)),
+        ];
+        for (input, result) in cases {
+            assert_eq!(
+                validate(input, Some(2)),
+                result.map_err(String::from),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            validate("1.5", Some(0)),
+            Err("Too many decimal places! Must have 0 or less".to_string())
+        );
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let cases = [
+            ("10", Ok(10.0)),
+            ("-10", Ok(-10.0)),
+            ("10.01", Err("Too big! Must be below or equal to 10")),
+            ("-10.01", Err("Too small! Must be above or equal to -10")),
+        ];
+        for (input, result) in cases {
+            assert_eq!(
+                validate(input, None),
+                result.map_err(String::from),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_numbers() {
+        let cases = [
+            ("", "You must enter a value"),
+            ("1.2.3", "Unable to convert input to number"),
+            ("e5", "Unable to convert input to number"),
+            ("NaN", "Must be a finite number"),
+            ("inf", "Must be a finite number"),
+            ("-inf", "Must be a finite number"),
+        ];
+        for (input, err) in cases {
+            assert_eq!(validate(input, None), Err(err.to_string()), "{input}");
+        }
+        let overflow = Float::<f32>::validator("1e39".to_string(), f32::MIN, f32::MAX, None);
+        assert_eq!(overflow, Err("Must be a finite number".to_string()));
+    }
+
+    #[test]
+    fn charset_follows_the_options() {
+        let float = Float::<f64>::new("");
+        assert!(float.charset().contains(&'-'));
+        let float = Float::<f64>::new("").min(0.0);
+        assert!(!float.charset().contains(&'-'));
+        let float = Float::<f64>::new("").min(0.0).scientific(true);
+        assert!(float
+            .charset()
+            .iter()
+            .all(|c| "0123456789.eE+-".contains(*c)));
+        assert!(float.charset().contains(&'-'));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn parses_decimals() {
+        use rust_decimal::Decimal;
+        let parsed =
+            Float::<Decimal>::validator("1.5e2".to_string(), Decimal::MIN, Decimal::MAX, Some(1));
+        assert_eq!(parsed, Ok(Decimal::new(150, 0)));
+        let parsed =
+            Float::<Decimal>::validator("0.10".to_string(), Decimal::MIN, Decimal::MAX, None);
+        assert_eq!(parsed.map(|d| d.to_string()), Ok("0.10".to_string()));
+    }
+}
@@ -0,0 +1,58 @@
+use crate::Result;
+use console::Term;
+
+/// A struct which can be filled in by asking a question for each of its fields
+///
+/// Deriving it picks the question from each field's type, `String` uses [`Input`](crate::Input),
+/// integers use [`Integer`](crate::Integer), `bool` uses [`Confirm`](crate::Confirm),
+/// `Vec<T>` uses [`MultiSelect`](crate::MultiSelect) and any other type uses
+/// [`Select`](crate::Select). Wrapping the type in an `Option` lets the question be skipped.
+///
+/// Fields are configured with `#[prompt("Title", key = value)]` where the keys are
+/// `default`, `min`, `max` and `validator`. A validator is a function like the one given to
+/// [`Input::validator`](crate::Input::validator) and replaces the question picked from the type.
+/// Setting `password = true` on a `String` field asks for it with [`Password`](crate::Password)
+/// so it isn't shown while typing
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::{Form, SelectEnum};
+///
+/// #[derive(SelectEnum, Debug)]
+/// enum Language {
+///     Rust,
+///     Go,
+///     C,
+/// }
+///
+/// #[derive(Form, Debug)]
+/// struct Profile {
+///     #[prompt("What is your name?", validator = name)]
+///     name: String,
+///     #[prompt("How old are you?", max = 120)]
+///     age: Option<u8>,
+///     #[prompt("What languages do you use?", min = 1)]
+///     languages: Vec<Language>,
+///     #[prompt("Do you want the newsletter?", default = false)]
+///     newsletter: bool,
+/// }
+///
+/// fn name(input: String) -> Result<String, String> {
+///     if input.is_empty() {
+///         return Err("You must enter a name".to_string());
+///     }
+///     Ok(input)
+/// }
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let profile = Profile::ask(&term)?;
+/// println!("You answered {profile:?}");
+/// # Ok(())
+/// # }
+/// ```
+pub trait Form: Sized {
+    /// Ask the question for each field in order getting the filled struct as a result
+    fn ask(term: &Term) -> Result<Self>;
+}
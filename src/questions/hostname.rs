@@ -0,0 +1,112 @@
+#![cfg(feature = "net")]
+
+use crate::{Input, Result};
+use console::Term;
+use std::result;
+
+/// Get a hostname such as `example.com` following RFC 1123 from the user
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::Hostname;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let host = Hostname::new("What is the database host?").ask(&term)?;
+/// println!("Connecting to {host}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct Hostname {
+    title: String,
+}
+
+impl Hostname {
+    /// Creates a hostname input with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+        }
+    }
+
+    /// Ask the question getting the inputted hostname as a result
+    pub fn ask(&self, term: &Term) -> Result<String> {
+        Input::new(self.title.clone())
+            .validator(Self::validator)
+            .ask(term)
+    }
+
+    fn validator(input: String) -> result::Result<String, String> {
+        let input = input.trim();
+        let err = if input.is_empty() {
+            "You must enter a hostname"
+        } else if input.len() > 253 {
+            "The hostname is too long"
+        } else if !input
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+        {
+            "An invalid character is present"
+        } else if input.split('.').any(|label| label.is_empty()) {
+            "Invalid placement of a '.'"
+        } else if input.split('.').any(|label| label.len() > 63) {
+            "A label is too long"
+        } else if input
+            .split('.')
+            .any(|label| label.starts_with('-') || label.ends_with('-'))
+        {
+            "Labels can't start or end with a '-'"
+        } else {
+            return Ok(input.to_string());
+        };
+        Err(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_hostnames() {
+        let label = "a".repeat(63);
+        let cases = [
+            "localhost",
+            " example.com ",
+            "my-host.example.co.uk",
+            "123.example",
+            &label,
+        ];
+        for input in cases {
+            assert_eq!(
+                Hostname::validator(input.to_string()),
+                Ok(input.trim().to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn explains_invalid_hostnames() {
+        let long_label = format!("{}.com", "a".repeat(64));
+        let long_name = vec!["a".repeat(50); 6].join(".");
+        let cases = [
+            ("", "You must enter a hostname"),
+            (long_name.as_str(), "The hostname is too long"),
+            ("exa_mple.com", "An invalid character is present"),
+            ("example.com.", "Invalid placement of a '.'"),
+            (".example.com", "Invalid placement of a '.'"),
+            ("example..com", "Invalid placement of a '.'"),
+            (long_label.as_str(), "A label is too long"),
+            ("-example.com", "Labels can't start or end with a '-'"),
+            ("example-.com", "Labels can't start or end with a '-'"),
+        ];
+        for (input, err) in cases {
+            assert_eq!(
+                Hostname::validator(input.to_string()),
+                Err(err.to_string()),
+                "{input}"
+            );
+        }
+    }
+}
@@ -6,10 +6,48 @@ use std::result;
 
 pub struct NoValidator;
 pub struct Validator<T> {
-    method: Box<ValidatorFunc<T>>,
+    pub(crate) method: Box<ValidatorFunc<T>>,
 }
 
-type ValidatorFunc<T> = dyn Fn(String) -> result::Result<T, String>;
+pub(crate) type ValidatorFunc<T> = dyn Fn(String) -> result::Result<T, String>;
+type DisplayFunc<T> = dyn Fn(&T) -> String;
+
+const SUGGESTIONS: usize = 5;
+
+/// Provides suggestions for an [`Input`] while the user is typing
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::Input;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let commands = ["build", "check", "clean", "test"];
+/// let answer = Input::new("Which command?")
+///     .suggester(move |input: &str, _cursor: usize| {
+///         commands
+///             .iter()
+///             .filter(|c| c.starts_with(input))
+///             .map(|c| c.to_string())
+///             .collect()
+///     })
+///     .validator(|input| Ok(input))
+///     .ask(&term)?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait Suggester {
+    /// Get the suggestions for the current input and cursor position,
+    /// an accepted suggestion replaces the whole input
+    fn suggest(&self, input: &str, cursor: usize) -> Vec<String>;
+}
+
+impl<F: Fn(&str, usize) -> Vec<String>> Suggester for F {
+    fn suggest(&self, input: &str, cursor: usize) -> Vec<String> {
+        self(input, cursor)
+    }
+}
 
 /// Get a single enum variant input from the user
 ///
@@ -35,6 +73,8 @@ pub struct Input<T, Validator = NoValidator> {
     default: Option<String>,
     charset: Option<Vec<char>>,
     validator: Validator,
+    display: Option<Box<DisplayFunc<T>>>,
+    suggester: Option<Box<dyn Suggester>>,
     data: PhantomData<T>,
 }
 
@@ -46,6 +86,8 @@ impl<T> Input<T, NoValidator> {
             default: None,
             charset: None,
             validator: NoValidator,
+            display: None,
+            suggester: None,
             data: PhantomData::<T>,
         }
     }
@@ -63,6 +105,8 @@ impl<T> Input<T, NoValidator> {
             default: self.default,
             charset: self.charset,
             validator,
+            display: self.display,
+            suggester: self.suggester,
             data: Default::default(),
         }
     }
@@ -71,72 +115,61 @@ impl<T> Input<T, NoValidator> {
 impl<T> Input<T, Validator<T>> {
     /// Ask the question getting the validated type as a result
     pub fn ask(&self, term: &Term) -> Result<T> {
-        term.write_line(&formatted_question(
-            self.title.clone(),
-            &[("enter", "proceed")],
-        ))?;
+        let mut actions = vec![("enter", "proceed")];
+        if self.suggester.is_some() {
+            actions.insert(0, ("tab", "accept"));
+        }
+        term.write_line(&formatted_question(self.title.clone(), &actions))?;
 
-        let mut input = self.default.clone().unwrap_or_default();
-        let mut cursor = input.len();
+        let mut editor = LineEditor::new(self.default.as_deref().unwrap_or_default());
         let mut active_err_msg = false;
+        let mut suggestions = self.suggestions(&editor);
+        let mut suggestion = 0;
 
         loop {
-            term.write_str(&format!(
-                "{} {input}",
-                style(ARROW.to_string().repeat(2)).red()
-            ))?;
-
-            term.move_cursor_left(100)?;
-            term.move_cursor_right(cursor + 3)?;
+            editor.write(term, Echo::Plain)?;
+            let rendered_suggestions =
+                Self::write_suggestions(term, &editor, &suggestions, suggestion)?;
+            editor.move_to_cursor(term, Echo::Plain)?;
 
             loop {
                 let key = term.read_key()?;
+                let previous = editor.text();
                 let rerender = match key {
-                    Key::Char(c) if !c.is_control() => {
-                        if let Some(charset) = &self.charset {
-                            if !charset.contains(&c) {
-                                continue;
-                            }
-                        }
-                        cursor += 1;
-                        if cursor == input.len() + 1 {
-                            input.push(c);
-                            term.write_str(&c.to_string())?;
-                            false
-                        } else {
-                            input.insert(cursor - 1, c);
-                            true
-                        }
+                    Key::Char(c)
+                        if self
+                            .charset
+                            .as_ref()
+                            .is_some_and(|charset| !charset.contains(&c)) =>
+                    {
+                        false
                     }
-                    Key::Backspace if !input.is_empty() => {
-                        input.remove(cursor - 1);
-                        cursor -= 1;
-                        if cursor == input.len() {
-                            term.clear_chars(1)?;
-                            false
-                        } else {
-                            true
-                        }
+                    Key::Tab | Key::ArrowRight if editor.at_end() && !suggestions.is_empty() => {
+                        editor.set_text(&suggestions[suggestion]);
+                        true
                     }
-                    Key::ArrowLeft if cursor > 0 => {
-                        cursor -= 1;
-                        term.move_cursor_left(1)?;
-                        false
+                    Key::ArrowUp if !suggestions.is_empty() => {
+                        suggestion = suggestion.checked_sub(1).unwrap_or(suggestions.len() - 1);
+                        true
                     }
-                    Key::ArrowRight if cursor < input.len() => {
-                        cursor += 1;
-                        term.move_cursor_right(1)?;
-                        false
+                    Key::ArrowDown if !suggestions.is_empty() => {
+                        suggestion = (suggestion + 1) % suggestions.len();
+                        true
                     }
                     Key::Enter => {
+                        let input = editor.text();
                         let validated_input = (self.validator.method)(input.clone());
+                        Self::clear_suggestions(term, rendered_suggestions)?;
                         match validated_input {
                             Ok(ans) => {
                                 term.clear_line()?;
                                 term.clear_last_lines(if !active_err_msg { 1 } else { 2 })?;
                                 term.write_line(&formatted_answered_question(
                                     self.title.clone(),
-                                    input.clone(),
+                                    match &self.display {
+                                        Some(display) => display(&ans),
+                                        None => input,
+                                    },
                                 ))?;
                                 return Ok(ans);
                             }
@@ -155,17 +188,80 @@ impl<T> Input<T, Validator<T>> {
                             }
                         }
                     }
-
-                    _ => false,
+                    key => editor.handle_key(&key),
                 };
 
+                if self.suggester.is_some() && editor.text() != previous {
+                    suggestions = self.suggestions(&editor);
+                    suggestion = 0;
+                }
+
                 if rerender {
+                    Self::clear_suggestions(term, rendered_suggestions)?;
                     term.clear_line()?;
                     break;
                 }
             }
         }
     }
+
+    fn suggestions(&self, editor: &LineEditor) -> Vec<String> {
+        let input = editor.text();
+        match &self.suggester {
+            Some(suggester) => suggester
+                .suggest(&input, editor.byte_cursor())
+                .into_iter()
+                .filter(|s| *s != input)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Writes the highlighted suggestion as ghost text and the others below the input,
+    /// returning the number of lines written below
+    fn write_suggestions(
+        term: &Term,
+        editor: &LineEditor,
+        suggestions: &[String],
+        suggestion: usize,
+    ) -> Result<usize> {
+        if let Some(ghost) = suggestions
+            .get(suggestion)
+            .and_then(|s| s.strip_prefix(&editor.text()))
+            .filter(|_| editor.at_end())
+        {
+            term.write_str(&style(ghost).dim().to_string())?;
+        }
+
+        if suggestions.len() < 2 {
+            return Ok(0);
+        }
+        let start = (suggestion + 1).saturating_sub(SUGGESTIONS);
+        let shown = suggestions.iter().enumerate().skip(start).take(SUGGESTIONS);
+        let mut lines = 0;
+        for (n, s) in shown {
+            let line = if n == suggestion {
+                format!("   {} {}", style(ARROW).red(), style(s).red().bold())
+            } else {
+                format!("     {}", style(s).dim())
+            };
+            term.write_str(&format!("\n{line}"))?;
+            lines += 1;
+        }
+        term.move_cursor_up(lines)?;
+        Ok(lines)
+    }
+
+    fn clear_suggestions(term: &Term, lines: usize) -> Result<()> {
+        if lines > 0 {
+            term.move_cursor_down(lines)?;
+            for _ in 0..lines {
+                term.clear_line()?;
+                term.move_cursor_up(1)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<T, Validator> Input<T, Validator> {
@@ -175,9 +271,148 @@ impl<T, Validator> Input<T, Validator> {
         self
     }
 
+    /// Set how the answer is shown once the question has been answered,
+    /// defaults to the inputted text
+    pub fn display(mut self, value: impl Fn(&T) -> String + 'static) -> Self {
+        self.display = Some(Box::new(value));
+        self
+    }
+
+    /// Set the suggester used to offer suggestions while typing,
+    /// pressing tab or right accepts the highlighted suggestion
+    pub fn suggester(mut self, value: impl Suggester + 'static) -> Self {
+        self.suggester = Some(Box::new(value));
+        self
+    }
+
     /// Set what characters are allowed to be inputted
     pub fn charset(mut self, value: impl IntoIterator<Item = char>) -> Self {
         self.charset = Some(value.into_iter().collect());
         self
     }
 }
+
+/// How the text of a [`LineEditor`] is shown while typing
+#[derive(Clone, Copy)]
+pub(super) enum Echo {
+    Plain,
+    Masked(char),
+    Hidden,
+}
+
+/// The single line editing shared by inputs, handling typing and moving the cursor
+pub(super) struct LineEditor {
+    input: Vec<char>,
+    cursor: usize,
+}
+
+impl LineEditor {
+    pub(super) fn new(text: &str) -> Self {
+        let input: Vec<char> = text.chars().collect();
+        Self {
+            cursor: input.len(),
+            input,
+        }
+    }
+
+    pub(super) fn text(&self) -> String {
+        self.input.iter().collect()
+    }
+
+    pub(super) fn chars(&self) -> &[char] {
+        &self.input
+    }
+
+    pub(super) fn set_text(&mut self, text: &str) {
+        self.input = text.chars().collect();
+        self.cursor = self.input.len();
+    }
+
+    pub(super) fn at_end(&self) -> bool {
+        self.cursor == self.input.len()
+    }
+
+    /// The cursor as a byte offset into the text
+    fn byte_cursor(&self) -> usize {
+        self.input[..self.cursor].iter().map(|c| c.len_utf8()).sum()
+    }
+
+    /// Applies an editing key, returning whether the line needs to be rerendered
+    pub(super) fn handle_key(&mut self, key: &Key) -> bool {
+        match key {
+            Key::Char(c) if !c.is_control() => {
+                self.input.insert(self.cursor, *c);
+                self.cursor += 1;
+            }
+            Key::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.input.remove(self.cursor);
+            }
+            Key::Del if self.cursor < self.input.len() => {
+                self.input.remove(self.cursor);
+            }
+            Key::ArrowLeft if self.cursor > 0 => self.cursor -= 1,
+            Key::ArrowRight if self.cursor < self.input.len() => self.cursor += 1,
+            Key::Home => self.cursor = 0,
+            Key::End => self.cursor = self.input.len(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Writes the line without moving the cursor into place, so more can be written after it
+    pub(super) fn write(&self, term: &Term, echo: Echo) -> Result<()> {
+        let shown: String = match echo {
+            Echo::Plain => self.text(),
+            Echo::Masked(mask) => mask.to_string().repeat(self.input.len()),
+            Echo::Hidden => String::new(),
+        };
+        term.write_str(&format!(
+            "{} {shown}",
+            style(ARROW.to_string().repeat(2)).red()
+        ))?;
+        Ok(())
+    }
+
+    pub(super) fn move_to_cursor(&self, term: &Term, echo: Echo) -> Result<()> {
+        let cursor = match echo {
+            Echo::Hidden => 0,
+            Echo::Plain | Echo::Masked(_) => self.cursor,
+        };
+        term.move_cursor_left(100)?;
+        term.move_cursor_right(cursor + 3)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edits_at_the_cursor() {
+        let mut editor = LineEditor::new("héllo");
+        assert!(editor.at_end());
+        editor.handle_key(&Key::ArrowLeft);
+        editor.handle_key(&Key::ArrowLeft);
+        editor.handle_key(&Key::Backspace);
+        assert_eq!(editor.text(), "hélo");
+        assert_eq!(editor.byte_cursor(), 3);
+        editor.handle_key(&Key::Char('!'));
+        editor.handle_key(&Key::Home);
+        editor.handle_key(&Key::Del);
+        assert_eq!(editor.text(), "é!lo");
+        editor.handle_key(&Key::End);
+        assert!(editor.at_end());
+    }
+
+    #[test]
+    fn ignores_keys_it_cannot_apply() {
+        let mut editor = LineEditor::new("");
+        assert!(!editor.handle_key(&Key::Backspace));
+        assert!(!editor.handle_key(&Key::ArrowRight));
+        assert!(!editor.handle_key(&Key::Char('\t')));
+        assert!(!editor.handle_key(&Key::Enter));
+        assert_eq!(editor.text(), "");
+    }
+}
@@ -27,6 +27,7 @@ where
     T::Err: IntError,
 {
     title: String,
+    default: Option<T>,
     min: T,
     max: T,
 }
@@ -39,11 +40,18 @@ where
     pub fn new(title: impl Into<String>) -> Self {
         Self {
             title: title.into(),
+            default: None,
             min: T::min_value(),
             max: T::max_value(),
         }
     }
 
+    /// Set the default value of the input
+    pub fn default(mut self, value: T) -> Self {
+        self.default = Some(value);
+        self
+    }
+
     /// Sets the min value accepted by the input
     pub fn min(mut self, min: T) -> Self {
         self.min = min;
@@ -60,13 +68,38 @@ where
     pub fn ask(&self, term: &Term) -> Result<T> {
         let min = self.min;
         let max = self.max;
-        Input::new(self.title.clone())
+        let mut input = Input::new(self.title.clone())
             .charset(self.charset())
-            .validator(move |input| Self::validator(input, min, max))
-            .ask(term)
+            .validator(move |input| Self::validator(input, min, max));
+        if let Some(default) = self.default {
+            input = input.default(default.to_string());
+        }
+        input.ask(term)
+    }
+
+    /// Ask the question optionally getting either the inputted integer or none if left empty as a result
+    pub fn ask_opt(&self, term: &Term) -> Result<Option<T>> {
+        let min = self.min;
+        let max = self.max;
+        let mut input = Input::new(self.title.clone())
+            .charset(self.charset())
+            .validator(move |input| {
+                if input.is_empty() {
+                    return Ok(None);
+                }
+                Self::validator(input, min, max).map(Some)
+            })
+            .display(|ans| match ans {
+                Some(n) => n.to_string(),
+                None => String::from("Skipped"),
+            });
+        if let Some(default) = self.default {
+            input = input.default(default.to_string());
+        }
+        input.ask(term)
     }
 
-    fn validator(input: String, min: T, max: T) -> result::Result<T, String> {
+    pub(crate) fn validator(input: String, min: T, max: T) -> result::Result<T, String> {
         let res = input.parse::<T>();
         match res {
             Ok(ans) => {
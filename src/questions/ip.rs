@@ -0,0 +1,229 @@
+#![cfg(feature = "net")]
+
+use crate::{Input, Result};
+use console::Term;
+use std::marker::PhantomData;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::result;
+use std::str::FromStr;
+
+/// Get an IP address input from the user, either [`IpAddr`], [`Ipv4Addr`] or [`Ipv6Addr`]
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::IpAddress;
+/// use std::net::Ipv4Addr;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let gateway = IpAddress::<Ipv4Addr>::new("What is the gateway address?").ask(&term)?;
+/// println!("Routing through {gateway}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct IpAddress<T: IpValue = IpAddr> {
+    title: String,
+    data: PhantomData<T>,
+}
+
+pub trait IpValue: FromStr + ToString + 'static {
+    const V4: bool;
+    const V6: bool;
+}
+
+impl IpValue for IpAddr {
+    const V4: bool = true;
+    const V6: bool = true;
+}
+
+impl IpValue for Ipv4Addr {
+    const V4: bool = true;
+    const V6: bool = false;
+}
+
+impl IpValue for Ipv6Addr {
+    const V4: bool = false;
+    const V6: bool = true;
+}
+
+impl<T: IpValue> IpAddress<T> {
+    /// Creates an IP address input with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            data: PhantomData::<T>,
+        }
+    }
+
+    /// Ask the question getting the inputted IP address as a result
+    pub fn ask(&self, term: &Term) -> Result<T> {
+        Input::new(self.title.clone())
+            .validator(Self::validator)
+            .ask(term)
+    }
+
+    fn validator(input: String) -> result::Result<T, String> {
+        T::from_str(input.trim()).map_err(|_| ip_error(input.trim(), T::V4, T::V6).to_string())
+    }
+}
+
+/// Describes why the input isn't a valid IP address, the standard library's parser
+/// doesn't give a reason so the input is checked again here
+pub(crate) fn ip_error(input: &str, v4: bool, v6: bool) -> &'static str {
+    if input.is_empty() {
+        return "You must enter an IP address";
+    }
+    match (input.contains(':'), v4, v6) {
+        (true, _, false) => "Must be an IPv4 address",
+        (false, false, _) => "Must be an IPv6 address",
+        (true, _, true) => ipv6_error(input),
+        (false, true, _) => ipv4_error(input),
+    }
+}
+
+fn ipv4_error(input: &str) -> &'static str {
+    if !input.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return "An invalid character is present";
+    }
+    let parts: Vec<&str> = input.split('.').collect();
+    if parts.iter().any(|p| p.is_empty()) {
+        return "Invalid placement of a '.'";
+    }
+    if parts.len() != 4 {
+        return "Must have 4 parts separated by '.'";
+    }
+    if parts.iter().any(|p| p.len() > 1 && p.starts_with('0')) {
+        return "Parts can't have leading zeros";
+    }
+    if parts.iter().any(|p| p.parse::<u8>().is_err()) {
+        return "Each part must be between 0 and 255";
+    }
+    "Invalid IPv4 address"
+}
+
+fn ipv6_error(input: &str) -> &'static str {
+    if !input
+        .chars()
+        .all(|c| c.is_ascii_hexdigit() || c == ':' || c == '.')
+    {
+        return "An invalid character is present";
+    }
+    if input.contains(":::")
+        || (input.starts_with(':') && !input.starts_with("::"))
+        || (input.ends_with(':') && !input.ends_with("::"))
+    {
+        return "Invalid placement of a ':'";
+    }
+    let compressed = input.matches("::").count();
+    if compressed > 1 {
+        return "'::' can only appear once";
+    }
+
+    let groups: Vec<&str> = input.split(':').filter(|g| !g.is_empty()).collect();
+    // An IPv4 address can be embedded as the last two groups
+    let (groups, embedded) = match groups.split_last() {
+        Some((last, rest)) if last.contains('.') && !input.ends_with(':') => {
+            if Ipv4Addr::from_str(last).is_err() {
+                return "The embedded IPv4 address is invalid";
+            }
+            (rest, 2)
+        }
+        _ => (groups.as_slice(), 0),
+    };
+    if groups.iter().any(|g| g.contains('.')) {
+        return "Invalid placement of a '.'";
+    }
+    if groups.iter().any(|g| g.len() > 4) {
+        return "Each group must have 4 or fewer digits";
+    }
+    let count = groups.len() + embedded;
+    if compressed == 0 && count != 8 {
+        return "Must have 8 groups separated by ':'";
+    }
+    if compressed == 1 && count > 7 {
+        return "Too many groups for '::' to be used";
+    }
+    "Invalid IPv6 address"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_addresses() {
+        let cases = [
+            "127.0.0.1",
+            "0.0.0.0",
+            "255.255.255.255",
+            "::",
+            "::1",
+            "fe80::1:2",
+            "::ffff:1.2.3.4",
+        ];
+        for input in cases {
+            assert!(
+                IpAddress::<IpAddr>::validator(input.to_string()).is_ok(),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            IpAddress::<Ipv4Addr>::validator(" 10.0.0.1 ".to_string()),
+            Ok(Ipv4Addr::new(10, 0, 0, 1))
+        );
+    }
+
+    #[test]
+    fn explains_invalid_ipv4_addresses() {
+        let cases = [
+            ("", "You must enter an IP address"),
+            ("1.2.3.a", "An invalid character is present"),
+            ("1..2.3", "Invalid placement of a '.'"),
+            ("1.2.3.", "Invalid placement of a '.'"),
+            ("1.2.3", "Must have 4 parts separated by '.'"),
+            ("1.2.3.4.5", "Must have 4 parts separated by '.'"),
+            ("01.2.3.4", "Parts can't have leading zeros"),
+            ("1.2.3.00", "Parts can't have leading zeros"),
+            ("256.0.0.1", "Each part must be between 0 and 255"),
+            ("1.2.3.999", "Each part must be between 0 and 255"),
+        ];
+        for (input, err) in cases {
+            assert_eq!(ip_error(input, true, true), err, "{input}");
+        }
+    }
+
+    #[test]
+    fn explains_invalid_ipv6_addresses() {
+        let cases = [
+            ("::g", "An invalid character is present"),
+            (":1:2:3:4:5:6:7", "Invalid placement of a ':'"),
+            ("1:2:3:4:5:6:7:", "Invalid placement of a ':'"),
+            ("1:::2", "Invalid placement of a ':'"),
+            ("1::2::3", "'::' can only appear once"),
+            ("1:2.3.4.5::", "Invalid placement of a '.'"),
+            ("::1.2.3", "The embedded IPv4 address is invalid"),
+            ("12345::", "Each group must have 4 or fewer digits"),
+            ("1:2:3:4:5:6:7", "Must have 8 groups separated by ':'"),
+            ("1:2:3:4:5:6:7:8:9", "Must have 8 groups separated by ':'"),
+            ("1:2:3:4:5:6:7::8", "Too many groups for '::' to be used"),
+            (
+                "1:2:3:4:5:6::1.2.3.4",
+                "Too many groups for '::' to be used",
+            ),
+        ];
+        for (input, err) in cases {
+            assert_eq!(ip_error(input, true, true), err, "{input}");
+        }
+    }
+
+    #[test]
+    fn rejects_the_other_version() {
+        assert_eq!(ip_error("::1", true, false), "Must be an IPv4 address");
+        assert_eq!(ip_error("1.2.3.4", false, true), "Must be an IPv6 address");
+        assert_eq!(
+            IpAddress::<Ipv6Addr>::validator("1.2.3.4".to_string()),
+            Err("Must be an IPv6 address".to_string())
+        );
+    }
+}
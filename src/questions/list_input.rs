@@ -0,0 +1,354 @@
+use crate::questions::input::{Echo, LineEditor};
+use crate::questions::{formatted_answered_question, formatted_question, ARROW};
+use crate::{NoValidator, Result, Validator};
+use console::{style, Key, Term};
+use std::marker::PhantomData;
+use std::result;
+
+/// Get a list of items from the user, each validated on its own
+///
+/// Pressing enter adds the typed item, commas split pasted text into separate items
+/// and pressing enter with nothing typed submits the list. Pressing up selects an
+/// existing item which can then be removed with backspace or delete
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::ListInput;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let tags = ListInput::new("Which tags should be added?")
+///     .min(1)
+///     .max(5)
+///     .validator(|input| {
+///         if input.contains(' ') {
+///             return Err("Tags can't contain spaces".to_string());
+///         }
+///         Ok(input.to_lowercase())
+///     })
+///     .ask(&term)?;
+/// println!("Adding {tags:?}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct ListInput<T, Validator = NoValidator> {
+    title: String,
+    min: usize,
+    max: usize,
+    validator: Validator,
+    data: PhantomData<T>,
+}
+
+impl<T> ListInput<T, NoValidator> {
+    /// Creates a list input with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            min: 0,
+            max: usize::MAX,
+            validator: NoValidator,
+            data: PhantomData::<T>,
+        }
+    }
+
+    /// Set the function for validating and returning the parsed type of each item
+    pub fn validator(
+        self,
+        value: impl Fn(String) -> result::Result<T, String> + 'static,
+    ) -> ListInput<T, Validator<T>> {
+        let validator = Validator::<T> {
+            method: Box::new(value),
+        };
+        ListInput::<T, Validator<T>> {
+            title: self.title,
+            min: self.min,
+            max: self.max,
+            validator,
+            data: Default::default(),
+        }
+    }
+}
+
+impl<T> ListInput<T, Validator<T>> {
+    /// Ask the question getting the list of validated items as a result
+    pub fn ask(&self, term: &Term) -> Result<Vec<T>> {
+        term.write_line(&formatted_question(
+            self.title.clone(),
+            &[("enter", "add"), ("up", "remove")],
+        ))?;
+
+        let mut items: Vec<(String, T)> = Vec::new();
+        let mut editor = ItemEditor::new();
+
+        loop {
+            let displays: Vec<&str> = items.iter().map(|(display, _)| display.as_str()).collect();
+            let hint = if items.len() < self.min {
+                "type an item"
+            } else {
+                "press enter to proceed"
+            };
+            let rendered = editor.write(term, &displays, None, hint)?;
+
+            loop {
+                let key = term.read_key()?;
+                let rerender = match key {
+                    Key::Char(',') if editor.highlighted.is_none() => {
+                        editor.err_msg = self.add(&mut items, &mut editor).err();
+                        true
+                    }
+                    Key::Backspace | Key::Del if editor.highlighted.is_some() => {
+                        editor.remove_highlighted(&mut items);
+                        true
+                    }
+                    Key::Escape | Key::Enter if editor.highlighted.is_some() => {
+                        editor.highlighted = None;
+                        true
+                    }
+                    Key::Enter if !editor.is_empty() => {
+                        editor.err_msg = self.add(&mut items, &mut editor).err();
+                        true
+                    }
+                    Key::Enter if items.len() < self.min => {
+                        editor.err_msg = Some(format!("Must enter at least {}", self.min));
+                        true
+                    }
+                    Key::Enter => {
+                        term.clear_line()?;
+                        term.clear_last_lines(rendered + 1)?;
+                        term.write_line(&formatted_answered_question(
+                            self.title.clone(),
+                            if !items.is_empty() {
+                                items
+                                    .iter()
+                                    .map(|(display, _)| display.as_str())
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            } else {
+                                "Skipped".to_string()
+                            },
+                        ))?;
+                        return Ok(items.into_iter().map(|(_, item)| item).collect());
+                    }
+                    key => editor.handle_key(&key, items.len()),
+                };
+
+                if rerender {
+                    term.clear_line()?;
+                    term.clear_last_lines(rendered)?;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Validates and adds each comma separated item in the input, leaving anything
+    /// that couldn't be added in the input
+    fn add(
+        &self,
+        items: &mut Vec<(String, T)>,
+        editor: &mut ItemEditor,
+    ) -> result::Result<(), String> {
+        let text = editor.take_input();
+        let mut parts = text.split(',').map(str::trim).filter(|p| !p.is_empty());
+        while let Some(part) = parts.next() {
+            let result = if items.len() >= self.max {
+                Err(format!("Must enter {} or less", self.max))
+            } else {
+                (self.validator.method)(part.to_string())
+            };
+            match result {
+                Ok(item) => items.push((part.to_string(), item)),
+                Err(msg) => {
+                    let rest: Vec<&str> = std::iter::once(part).chain(parts).collect();
+                    editor.set_input(&rest.join(", "));
+                    return Err(msg);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T, Validator> ListInput<T, Validator> {
+    /// Set the minimum number of items allowed
+    pub fn min(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the maximum number of items allowed
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+}
+
+/// The line of input below the items of a list or map input, with up and down highlighting
+/// one of the items above it
+pub(super) struct ItemEditor {
+    line: LineEditor,
+    pub(super) highlighted: Option<usize>,
+    pub(super) err_msg: Option<String>,
+}
+
+impl ItemEditor {
+    pub(super) fn new() -> Self {
+        Self {
+            line: LineEditor::new(""),
+            highlighted: None,
+            err_msg: None,
+        }
+    }
+
+    /// Whether nothing has been typed
+    pub(super) fn is_empty(&self) -> bool {
+        self.line.chars().is_empty()
+    }
+
+    /// The typed text
+    pub(super) fn text(&self) -> String {
+        self.line.text()
+    }
+
+    /// Take the typed text, leaving the input empty
+    pub(super) fn take_input(&mut self) -> String {
+        let text = self.line.text();
+        self.line.set_text("");
+        text
+    }
+
+    /// Replace the typed text, moving the cursor to the end
+    pub(super) fn set_input(&mut self, text: &str) {
+        self.line.set_text(text);
+    }
+
+    /// Remove the highlighted item, moving the highlight to the item now in its place
+    pub(super) fn remove_highlighted<T>(&mut self, items: &mut Vec<T>) {
+        let Some(n) = self.highlighted else {
+            return;
+        };
+        items.remove(n);
+        self.highlighted = (!items.is_empty()).then(|| n.min(items.len() - 1));
+    }
+
+    /// Handle a key editing the input or moving the highlight through the given number of
+    /// items, returning whether anything changed
+    pub(super) fn handle_key(&mut self, key: &Key, items: usize) -> bool {
+        match key {
+            Key::Backspace if self.is_empty() && items > 0 => {
+                self.highlighted = Some(items - 1);
+            }
+            Key::ArrowUp if items > 0 => {
+                self.highlighted = Some(match self.highlighted {
+                    Some(n) => n.saturating_sub(1),
+                    None => items - 1,
+                });
+            }
+            Key::ArrowDown if self.highlighted.is_some() => {
+                self.highlighted = self.highlighted.filter(|n| *n + 1 < items).map(|n| n + 1);
+            }
+            key => {
+                if !self.line.handle_key(key) {
+                    return false;
+                }
+                if let Key::Char(_) = key {
+                    self.highlighted = None;
+                }
+            }
+        }
+        true
+    }
+
+    /// Writes the items, error and input returning the number of lines above the input,
+    /// the item being edited is dimmed and the hint is shown when nothing is typed
+    pub(super) fn write(
+        &self,
+        term: &Term,
+        items: &[impl AsRef<str>],
+        editing: Option<usize>,
+        hint: &str,
+    ) -> Result<usize> {
+        let (rows, _) = term.size();
+        let per_page = (rows as usize).saturating_sub(3).max(1);
+        let end = self
+            .highlighted
+            .or(editing)
+            .map(|n| n + 1)
+            .unwrap_or(items.len())
+            .max(per_page)
+            .min(items.len());
+        let start = end.saturating_sub(per_page);
+
+        for (n, item) in items.iter().enumerate().take(end).skip(start) {
+            let item = item.as_ref();
+            let arm = if self.highlighted == Some(n) {
+                format!("{} {}", style(ARROW).red(), style(item).red().bold())
+            } else if editing == Some(n) {
+                format!("  {}", style(item).dim())
+            } else {
+                format!("  {item}")
+            };
+            term.write_line(&arm)?;
+        }
+        if let Some(msg) = &self.err_msg {
+            term.write_line(&format!("{} {}", style('X').red(), style(msg).red()))?;
+        }
+
+        if self.is_empty() && self.highlighted.is_none() {
+            let prefix = style(ARROW.to_string().repeat(2)).red();
+            term.write_str(&format!("{prefix} {}", style(hint).dim()))?;
+        } else {
+            self.line.write(term, Echo::Plain)?;
+        }
+        self.line.move_to_cursor(term, Echo::Plain)?;
+        Ok(end - start + usize::from(self.err_msg.is_some()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlights_items_from_an_empty_input() {
+        let mut editor = ItemEditor::new();
+        assert!(editor.handle_key(&Key::Backspace, 2));
+        assert_eq!(editor.highlighted, Some(1));
+        assert!(editor.handle_key(&Key::ArrowUp, 2));
+        assert!(editor.handle_key(&Key::ArrowUp, 2));
+        assert_eq!(editor.highlighted, Some(0));
+        assert!(editor.handle_key(&Key::ArrowDown, 2));
+        assert!(editor.handle_key(&Key::ArrowDown, 2));
+        assert_eq!(editor.highlighted, None);
+        assert!(!editor.handle_key(&Key::ArrowDown, 2));
+    }
+
+    #[test]
+    fn typing_clears_the_highlight() {
+        let mut editor = ItemEditor::new();
+        editor.handle_key(&Key::ArrowUp, 1);
+        assert!(editor.handle_key(&Key::Char('a'), 1));
+        assert_eq!(editor.highlighted, None);
+        assert!(editor.handle_key(&Key::Backspace, 1));
+        assert!(editor.is_empty());
+        assert!(!editor.handle_key(&Key::Backspace, 0));
+        assert!(!editor.handle_key(&Key::Tab, 0));
+    }
+
+    #[test]
+    fn removing_moves_the_highlight() {
+        let mut editor = ItemEditor::new();
+        let mut items = vec!["a", "b", "c"];
+        editor.highlighted = Some(2);
+        editor.remove_highlighted(&mut items);
+        assert_eq!(
+            (items.clone(), editor.highlighted),
+            (vec!["a", "b"], Some(1))
+        );
+        editor.highlighted = Some(0);
+        editor.remove_highlighted(&mut items);
+        editor.remove_highlighted(&mut items);
+        assert_eq!((items, editor.highlighted), (vec![], None));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::questions::list_input::ItemEditor;
+use crate::questions::{formatted_answered_question, formatted_question};
+use crate::{NoValidator, Result, Validator};
+use console::{measure_text_width, Key, Term};
+use std::marker::PhantomData;
+use std::result;
+
+/// Get key value pairs such as environment variables from the user
+///
+/// Each pair is typed as `KEY=value` and added by pressing enter, a key that has
+/// already been added is rejected. Pressing enter with nothing typed submits the
+/// pairs in the order they were added. Pressing up selects an existing pair which
+/// can then be edited with enter or removed with backspace or delete
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::MapInput;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let env = MapInput::new("Which environment variables should be set?")
+///     .key_validator(|key| {
+///         if !key.chars().all(|c| c.is_ascii_uppercase() || c == '_') {
+///             return Err("Keys must be uppercase".to_string());
+///         }
+///         Ok(key)
+///     })
+///     .value_validator(|value| Ok(value))
+///     .ask(&term)?;
+/// println!("Setting {env:?}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct MapInput<K, V, KeyValidator = NoValidator, ValueValidator = NoValidator> {
+    title: String,
+    key_validator: KeyValidator,
+    value_validator: ValueValidator,
+    data: PhantomData<(K, V)>,
+}
+
+struct Row<K, V> {
+    key: String,
+    value: String,
+    parsed: (K, V),
+}
+
+impl<K, V> MapInput<K, V, NoValidator, NoValidator> {
+    /// Creates a map input with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            key_validator: NoValidator,
+            value_validator: NoValidator,
+            data: PhantomData::<(K, V)>,
+        }
+    }
+}
+
+impl<K, V, ValueValidator> MapInput<K, V, NoValidator, ValueValidator> {
+    /// Set the function for validating and returning the parsed type of each key
+    pub fn key_validator(
+        self,
+        value: impl Fn(String) -> result::Result<K, String> + 'static,
+    ) -> MapInput<K, V, Validator<K>, ValueValidator> {
+        let key_validator = Validator::<K> {
+            method: Box::new(value),
+        };
+        MapInput::<K, V, Validator<K>, ValueValidator> {
+            title: self.title,
+            key_validator,
+            value_validator: self.value_validator,
+            data: Default::default(),
+        }
+    }
+}
+
+impl<K, V, KeyValidator> MapInput<K, V, KeyValidator, NoValidator> {
+    /// Set the function for validating and returning the parsed type of each value
+    pub fn value_validator(
+        self,
+        value: impl Fn(String) -> result::Result<V, String> + 'static,
+    ) -> MapInput<K, V, KeyValidator, Validator<V>> {
+        let value_validator = Validator::<V> {
+            method: Box::new(value),
+        };
+        MapInput::<K, V, KeyValidator, Validator<V>> {
+            title: self.title,
+            key_validator: self.key_validator,
+            value_validator,
+            data: Default::default(),
+        }
+    }
+}
+
+impl<K: PartialEq, V> MapInput<K, V, Validator<K>, Validator<V>> {
+    /// Ask the question getting the validated keys and values in the order they were added,
+    /// each key appears only once
+    pub fn ask(&self, term: &Term) -> Result<Vec<(K, V)>> {
+        term.write_line(&formatted_question(
+            self.title.clone(),
+            &[("enter", "add"), ("up", "edit")],
+        ))?;
+
+        let mut rows: Vec<Row<K, V>> = Vec::new();
+        let mut editor = ItemEditor::new();
+        let mut editing: Option<usize> = None;
+
+        loop {
+            let key_width = rows
+                .iter()
+                .map(|row| measure_text_width(&row.key))
+                .max()
+                .unwrap_or_default();
+            let pairs: Vec<String> = rows
+                .iter()
+                .map(|row| {
+                    let padding = " ".repeat(key_width - measure_text_width(&row.key));
+                    format!("{}{padding} = {}", row.key, row.value)
+                })
+                .collect();
+            let rendered = editor.write(term, &pairs, editing, "KEY=value")?;
+
+            loop {
+                let key = term.read_key()?;
+                let rerender = match key {
+                    Key::Backspace | Key::Del if editor.highlighted.is_some() => {
+                        editor.remove_highlighted(&mut rows);
+                        editing = None;
+                        true
+                    }
+                    Key::Escape if editing.is_some() || editor.highlighted.is_some() => {
+                        if editing.take().is_some() {
+                            editor.take_input();
+                        }
+                        editor.highlighted = None;
+                        true
+                    }
+                    Key::Enter if editor.highlighted.is_some() => {
+                        let n = editor.highlighted.take().unwrap_or_default();
+                        editor.set_input(&format!("{}={}", rows[n].key, rows[n].value));
+                        editing = Some(n);
+                        editor.err_msg = None;
+                        true
+                    }
+                    Key::Enter if !editor.is_empty() => {
+                        match self.parse(&editor.text(), &rows, editing) {
+                            Ok(row) => {
+                                match editing.take() {
+                                    Some(n) => rows[n] = row,
+                                    None => rows.push(row),
+                                }
+                                editor.take_input();
+                                editor.err_msg = None;
+                            }
+                            Err(msg) => editor.err_msg = Some(msg),
+                        }
+                        true
+                    }
+                    Key::Enter => {
+                        term.clear_line()?;
+                        term.clear_last_lines(rendered + 1)?;
+                        term.write_line(&formatted_answered_question(
+                            self.title.clone(),
+                            if !rows.is_empty() {
+                                rows.iter()
+                                    .map(|row| row.key.as_str())
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            } else {
+                                "Skipped".to_string()
+                            },
+                        ))?;
+                        return Ok(rows.into_iter().map(|row| row.parsed).collect());
+                    }
+                    key => editor.handle_key(&key, rows.len()),
+                };
+
+                if rerender {
+                    term.clear_line()?;
+                    term.clear_last_lines(rendered)?;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Splits the input into a key and value, validating both and checking the key
+    /// isn't already used by any row other than the one being edited
+    fn parse(
+        &self,
+        input: &str,
+        rows: &[Row<K, V>],
+        editing: Option<usize>,
+    ) -> result::Result<Row<K, V>, String> {
+        let Some((key, value)) = input.split_once('=') else {
+            return Err("Must be in the form KEY=value".to_string());
+        };
+        let (key, value) = (key.trim().to_string(), value.trim().to_string());
+        let parsed_key = (self.key_validator.method)(key.clone())?;
+        let duplicate = rows
+            .iter()
+            .enumerate()
+            .any(|(n, row)| Some(n) != editing && row.parsed.0 == parsed_key);
+        if duplicate {
+            return Err(format!("{key} has already been set"));
+        }
+        let parsed_value = (self.value_validator.method)(value.clone())?;
+        Ok(Row {
+            key,
+            value,
+            parsed: (parsed_key, parsed_value),
+        })
+    }
+}
@@ -0,0 +1,263 @@
+use crate::questions::{
+    formatted_answered_question, formatted_question, page, page_len, per_page, prompts, ARROW,
+    FILLED_DOT, OUTLINE_DOT,
+};
+use crate::Error::Other;
+use crate::Result;
+use crate::SelectEnum;
+use console::{measure_text_width, style, Key, Term};
+use std::marker::PhantomData;
+
+/// Get an answer on the same scale for each of the rows from the user
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::{Matrix, SelectEnum};
+///
+/// #[derive(SelectEnum, Debug)]
+/// enum Statement {
+///     #[prompt("The docs are helpful")]
+///     Docs,
+///     #[prompt("The API is easy to use")]
+///     Api,
+/// }
+///
+/// #[derive(SelectEnum, Debug)]
+/// enum Agreement {
+///     Disagree,
+///     Neutral,
+///     Agree,
+/// }
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let answers = Matrix::<Statement, Agreement>::new("How do you feel about quizzard?")
+///     .required(true)
+///     .ask(&term)?;
+/// if let Some(agreement) = &answers[Statement::Docs.to_index()] {
+///     println!("On the docs you answered {agreement:?}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Matrix<R: SelectEnum, C: SelectEnum> {
+    title: String,
+    required: bool,
+    data: PhantomData<(R, C)>,
+}
+
+impl<R: SelectEnum, C: SelectEnum> Matrix<R, C> {
+    /// Creates a matrix with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            required: false,
+            data: PhantomData,
+        }
+    }
+
+    /// Set whether every row must be answered
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Ask the question getting the chosen column for each row as a result, indexed
+    /// by the row's position so `answers[row.to_index()]` is the answer to `row`,
+    /// with `None` for rows left unanswered
+    pub fn ask(&self, term: &Term) -> Result<Vec<Option<C>>> {
+        let rows = R::variant_count();
+        let columns = C::variant_count();
+        let mut cursor = (0, 0);
+        let mut answers: Vec<Option<usize>> = vec![None; rows];
+        let mut active_err_msg = false;
+        term.write_line(&formatted_question(
+            self.title.clone(),
+            &[("space", "select"), ("enter", "proceed")],
+        ))?;
+
+        loop {
+            let rendered_lines = Self::write_matrix(term, cursor, &answers)?;
+
+            loop {
+                let key = term.read_key()?;
+                let rerender = match key {
+                    Key::Char(' ') => {
+                        let (row, column) = cursor;
+                        if answers[row] == Some(column) {
+                            answers[row] = None;
+                        } else {
+                            answers[row] = Some(column);
+                            if row < rows - 1 {
+                                cursor.0 += 1;
+                            }
+                        }
+                        true
+                    }
+                    Key::Enter => {
+                        let missing = answers.iter().filter(|a| a.is_none()).count();
+                        if self.required && missing > 0 {
+                            term.clear_last_lines(rendered_lines)?;
+                            if active_err_msg {
+                                term.clear_last_lines(1)?;
+                            }
+                            term.write_line(&format!(
+                                "{} {}",
+                                style('X').red(),
+                                style(format!("Every row must be answered, {missing} left")).red()
+                            ))?;
+                            active_err_msg = true;
+                            break;
+                        }
+                        term.clear_last_lines(
+                            rendered_lines + if !active_err_msg { 1 } else { 2 },
+                        )?;
+                        term.write_line(&formatted_answered_question(
+                            self.title.clone(),
+                            if missing < rows {
+                                format!("{} of {rows} answered", rows - missing)
+                            } else {
+                                "Skipped".to_string()
+                            },
+                        ))?;
+                        let mut ans = Vec::with_capacity(rows);
+                        for column in answers {
+                            ans.push(match column {
+                                Some(column) => Some(
+                                    C::ask_variant(column, term)?
+                                        .ok_or(Other("Index out of range"))?,
+                                ),
+                                None => None,
+                            });
+                        }
+                        return Ok(ans);
+                    }
+                    Key::ArrowUp => {
+                        cursor.0 = if cursor.0 > 0 { cursor.0 - 1 } else { rows - 1 };
+                        true
+                    }
+                    Key::ArrowDown => {
+                        cursor.0 = if cursor.0 < rows - 1 { cursor.0 + 1 } else { 0 };
+                        true
+                    }
+                    Key::ArrowLeft => {
+                        cursor.1 = if cursor.1 > 0 {
+                            cursor.1 - 1
+                        } else {
+                            columns - 1
+                        };
+                        true
+                    }
+                    Key::ArrowRight => {
+                        cursor.1 = if cursor.1 < columns - 1 {
+                            cursor.1 + 1
+                        } else {
+                            0
+                        };
+                        true
+                    }
+                    Key::Char(c @ '1'..='9') => {
+                        let index = c.to_digit(10).unwrap() as usize - 1;
+                        if index < columns {
+                            cursor.1 = index;
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                    _ => false,
+                };
+
+                if rerender {
+                    term.clear_last_lines(rendered_lines)?;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Write the header and the page of rows the cursor is on, returning the number of lines written
+    fn write_matrix(
+        term: &Term,
+        cursor: (usize, usize),
+        answers: &[Option<usize>],
+    ) -> Result<usize> {
+        let label_width = prompts::<R>()
+            .map(measure_text_width)
+            .max()
+            .unwrap_or_default();
+        let mut widths: Vec<usize> = prompts::<C>().map(measure_text_width).collect();
+
+        // Fall back to numbered columns when the prompts don't fit
+        let (_, columns) = term.size();
+        let full_width = label_width + 2 + widths.iter().map(|w| w + 2).sum::<usize>();
+        let header = if full_width <= columns as usize {
+            prompts::<C>()
+                .enumerate()
+                .map(|(n, prompt)| {
+                    if n == cursor.1 {
+                        style(prompt).red().bold().to_string()
+                    } else {
+                        prompt.to_string()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("  ")
+        } else {
+            widths = (1..=C::variant_count())
+                .map(|n| n.to_string().len())
+                .collect();
+            let numbers = (1..=C::variant_count())
+                .map(|n| {
+                    if n - 1 == cursor.1 {
+                        style(n).red().bold().to_string()
+                    } else {
+                        n.to_string()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("  ");
+            format!("{numbers}  {}", style(C::prompt_at(cursor.1)).dim())
+        };
+        term.write_line(&format!("{}  {header}", " ".repeat(label_width + 2)))?;
+
+        let start = page(term, cursor.0) * per_page(term);
+        let shown = page_len(term, cursor.0, answers.len());
+        for (row, prompt) in prompts::<R>().enumerate().skip(start).take(shown) {
+            let padding = " ".repeat(label_width - measure_text_width(prompt));
+            let label = if row == cursor.0 {
+                format!(
+                    "{} {}{padding}",
+                    style(ARROW).red(),
+                    style(prompt).red().bold()
+                )
+            } else {
+                format!("  {prompt}{padding}")
+            };
+
+            let cells = widths
+                .iter()
+                .enumerate()
+                .map(|(column, width)| {
+                    let dot = if answers[row] == Some(column) {
+                        FILLED_DOT
+                    } else {
+                        OUTLINE_DOT
+                    };
+                    let left = width.saturating_sub(1) / 2;
+                    let right = width.saturating_sub(1) - left;
+                    let dot = if (row, column) == cursor {
+                        style(dot).red().bold().to_string()
+                    } else {
+                        dot.to_string()
+                    };
+                    format!("{}{dot}{}", " ".repeat(left), " ".repeat(right))
+                })
+                .collect::<Vec<_>>()
+                .join("  ");
+            term.write_line(&format!("{label}  {cells}"))?;
+        }
+        Ok(shown + 1)
+    }
+}
@@ -1,18 +1,83 @@
+pub use confirm::*;
+#[cfg(feature = "date")]
+pub use date::*;
+pub use duration::*;
+pub use dynamic_multiselect::*;
+pub use dynamic_select::*;
+pub use editor::*;
 #[cfg(feature = "email")]
 pub use email::*;
+pub use float::*;
+pub use form::*;
+#[cfg(feature = "net")]
+pub use hostname::*;
 pub use input::*;
 pub use integer::*;
+#[cfg(feature = "net")]
+pub use ip::*;
+pub use list_input::*;
+pub use map_input::*;
+pub use matrix::*;
 pub use multiselect::*;
+pub use password::*;
+pub use path::*;
+#[cfg(feature = "net")]
+pub use port::*;
+pub use rank::*;
+pub use rating::*;
 pub use select::*;
+pub use slider::*;
+#[cfg(feature = "net")]
+pub use socket_address::*;
+pub use table_select::*;
+pub use textarea::*;
+pub use time::*;
+pub use tree_multiselect::*;
+pub use tree_select::*;
+#[cfg(feature = "url")]
+pub use url_input::*;
 
-use console::style;
+use crate::Result;
+use console::{measure_text_width, style, truncate_str, Key, Term};
 
+mod confirm;
+#[cfg(feature = "date")]
+mod date;
+mod duration;
+mod dynamic_multiselect;
+mod dynamic_select;
+mod editor;
 #[cfg(feature = "email")]
 mod email;
+mod float;
+mod form;
+#[cfg(feature = "net")]
+mod hostname;
 mod input;
 mod integer;
+#[cfg(feature = "net")]
+mod ip;
+mod list_input;
+mod map_input;
+mod matrix;
 mod multiselect;
+mod password;
+mod path;
+#[cfg(feature = "net")]
+mod port;
+mod rank;
+mod rating;
 mod select;
+mod slider;
+#[cfg(feature = "net")]
+mod socket_address;
+mod table_select;
+mod textarea;
+mod time;
+mod tree_multiselect;
+mod tree_select;
+#[cfg(feature = "url")]
+mod url_input;
 
 const ARROW: char = '❯';
 const FILLED_DOT: char = '◉';
@@ -39,3 +104,434 @@ fn formatted_answered_question(title: String, answer: String) -> String {
         style(answer).dim(),
     )
 }
+
+fn multiline_summary(text: &str) -> String {
+    let mut lines = text.lines();
+    let first = lines.next().unwrap_or_default().to_string();
+    match lines.count() {
+        0 => first,
+        1 => format!("{first} (+1 line)"),
+        n => format!("{first} (+{n} lines)"),
+    }
+}
+
+/// The number of items shown per page, leaving room for the question and one
+/// other line, but always at least one even on a tiny terminal
+fn per_page(term: &Term) -> usize {
+    let (rows, _) = term.size();
+    (rows as usize).saturating_sub(2).max(1)
+}
+
+fn page(term: &Term, selected: usize) -> usize {
+    selected / per_page(term)
+}
+
+fn page_len(term: &Term, selected: usize, len: usize) -> usize {
+    let per_page = per_page(term);
+    let start = page(term, selected) * per_page;
+    len.saturating_sub(start).min(per_page)
+}
+
+fn formatted_filter(query: &str) -> String {
+    if query.is_empty() {
+        format!("{} {}", style('/').red(), style("Type to filter").dim())
+    } else {
+        format!("{} {query}", style('/').red())
+    }
+}
+
+fn formatted_prompt(prompt: &str, matches: &[usize], highlighted: bool) -> String {
+    prompt
+        .chars()
+        .enumerate()
+        .map(|(n, c)| {
+            let mut c = style(c);
+            if highlighted {
+                c = c.red().bold();
+            }
+            if matches.contains(&n) {
+                c = c.underlined();
+            }
+            c.to_string()
+        })
+        .collect()
+}
+
+/// A disabled option, dimmed with the reason it's disabled beside it
+fn formatted_disabled(
+    term: &Term,
+    marker: char,
+    prompt: &str,
+    matches: &[usize],
+    reason: &str,
+) -> String {
+    let prompt: String = prompt
+        .chars()
+        .enumerate()
+        .map(|(n, c)| {
+            let mut c = style(c).dim();
+            if matches.contains(&n) {
+                c = c.underlined();
+            }
+            c.to_string()
+        })
+        .collect();
+    with_description(
+        term,
+        format!("{} {prompt}", style(marker).dim()),
+        Some(reason),
+    )
+}
+
+/// Move the cursor up or down a list of options, wrapping around and skipping disabled options,
+/// which is none if every option is disabled
+fn move_cursor(
+    cursor: usize,
+    len: usize,
+    down: bool,
+    disabled: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    (1..=len)
+        .map(|step| {
+            if down {
+                (cursor + step) % len
+            } else {
+                (cursor + len - step) % len
+            }
+        })
+        .find(|n| !disabled(*n))
+}
+
+/// The first option which isn't disabled
+fn first_enabled(len: usize, disabled: impl Fn(usize) -> bool) -> Option<usize> {
+    (0..len).find(|n| !disabled(*n))
+}
+
+/// Add a dimmed description after an option, cut short so the line doesn't wrap
+fn with_description(term: &Term, option: String, description: Option<&str>) -> String {
+    let Some(description) = description else {
+        return option;
+    };
+    let (_, cols) = term.size();
+    let width = (cols as usize).saturating_sub(measure_text_width(&option) + 3);
+    if width < 4 {
+        return option;
+    }
+    let description = if measure_text_width(description) > width {
+        truncate_str(description, width, "…")
+    } else {
+        description.into()
+    };
+    format!("{option}  {}", style(description).dim())
+}
+
+/// The error shown when too few or too many options are selected
+fn formatted_min_max_error(selected: usize, min: usize, max: usize) -> String {
+    let msg = if selected < min {
+        format!("Must select at least {min}")
+    } else {
+        format!("Must select {max} or less")
+    };
+    format!("{} {}", style('X').red(), style(msg).red())
+}
+
+/// The answer shown once options are selected, listing their prompts
+fn formatted_selected(list: &OptionList, selected: &[usize]) -> String {
+    if selected.is_empty() {
+        return "Skipped".to_string();
+    }
+    selected
+        .iter()
+        .map(|n| list.prompt(*n))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The options of a select, handling the filter, moving the cursor past disabled options and
+/// rendering the page the cursor is on
+struct OptionList {
+    prompts: Vec<String>,
+    descriptions: Vec<Option<String>>,
+    reasons: Vec<Option<String>>,
+    filter: bool,
+    query: String,
+    /// The index and matched characters of each option matching the query
+    options: Vec<(usize, Vec<usize>)>,
+    /// The position of the cursor in the options, which is none when they're all disabled
+    cursor: Option<usize>,
+    /// A line written above the options
+    header: Option<String>,
+    /// What's shown for an option once it's chosen, the prompt when none are given
+    answers: Option<Vec<String>>,
+}
+
+impl OptionList {
+    fn new(
+        prompts: Vec<String>,
+        descriptions: Vec<Option<String>>,
+        reasons: Vec<Option<String>>,
+        filter: bool,
+    ) -> Self {
+        let mut list = Self {
+            prompts,
+            descriptions,
+            reasons,
+            filter,
+            query: String::new(),
+            options: Vec::new(),
+            cursor: None,
+            header: None,
+            answers: None,
+        };
+        list.update_options();
+        list
+    }
+
+    /// Set the line written above the options
+    fn with_header(mut self, header: String) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    /// Set what's shown for each option once it's chosen instead of its prompt
+    fn with_answers(mut self, answers: Vec<String>) -> Self {
+        self.answers = Some(answers);
+        self
+    }
+
+    fn prompt(&self, index: usize) -> &str {
+        &self.prompts[index]
+    }
+
+    fn is_disabled(&self, index: usize) -> bool {
+        self.reasons.get(index).is_some_and(Option::is_some)
+    }
+
+    /// The number of options which can be selected
+    fn enabled_len(&self) -> usize {
+        (0..self.prompts.len())
+            .filter(|n| !self.is_disabled(*n))
+            .count()
+    }
+
+    /// Move the cursor to the option at the index if it's shown
+    fn set_cursor(&mut self, index: usize) {
+        if let Some(n) = self.options.iter().position(|(i, _)| *i == index) {
+            self.cursor = Some(n);
+        }
+    }
+
+    /// The index of the option under the cursor if it can be selected
+    fn highlighted(&self) -> Option<usize> {
+        let (index, _) = self.options.get(self.cursor?)?;
+        (!self.is_disabled(*index)).then_some(*index)
+    }
+
+    fn update_options(&mut self) {
+        self.options = filter_prompts(&self.query, self.prompts.iter().map(String::as_str));
+        self.cursor = first_enabled(self.options.len(), |n| self.is_disabled(self.options[n].0));
+    }
+
+    /// Handle a key moving the cursor or changing the query, returning whether to rerender
+    fn handle_key(&mut self, key: &Key) -> bool {
+        match key {
+            Key::ArrowUp | Key::ArrowDown => {
+                let Some(cursor) = self.cursor else {
+                    return false;
+                };
+                self.cursor =
+                    move_cursor(cursor, self.options.len(), *key == Key::ArrowDown, |n| {
+                        self.is_disabled(self.options[n].0)
+                    });
+                true
+            }
+            Key::Char(c) if self.filter && !c.is_control() => {
+                self.query.push(*c);
+                self.update_options();
+                true
+            }
+            Key::Backspace if self.filter && !self.query.is_empty() => {
+                self.query.pop();
+                self.update_options();
+                true
+            }
+            Key::Char(c @ '1'..='9') => {
+                let n = c.to_digit(10).unwrap() as usize - 1;
+                match self.options.get(n) {
+                    Some((index, _)) if !self.is_disabled(*index) => {
+                        self.cursor = Some(n);
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// The options shown per page, leaving room for the question, filter and header
+    fn per_page(&self, term: &Term) -> usize {
+        let (rows, _) = term.size();
+        let above = 1 + self.filter as usize + self.header.is_some() as usize;
+        (rows as usize).saturating_sub(above).max(1)
+    }
+
+    /// The number of lines written by [`OptionList::write`]
+    fn rendered_lines(&self, term: &Term) -> usize {
+        let per_page = self.per_page(term);
+        let start = self.cursor.unwrap_or_default() / per_page * per_page;
+        let shown = self.options.len().saturating_sub(start).min(per_page);
+        shown.max(1) + self.filter as usize + self.header.is_some() as usize
+    }
+
+    /// Write the filter and the page of options the cursor is on, marking the selected options
+    /// with dots if some are given and the highlighted option with an arrow otherwise
+    fn write(&self, term: &Term, selected: Option<&[usize]>) -> Result<()> {
+        if self.filter {
+            term.write_line(&formatted_filter(&self.query))?;
+        }
+        if let Some(header) = &self.header {
+            term.write_line(header)?;
+        }
+        if self.options.is_empty() {
+            term.write_line(&format!("  {}", style("No matches").dim()))?;
+        }
+
+        let per_page = self.per_page(term);
+        let start = self.cursor.unwrap_or_default() / per_page * per_page;
+        let shown = self.options.iter().enumerate().skip(start).take(per_page);
+        for (n, (index, matches)) in shown {
+            let prompt = self.prompt(*index);
+            let highlighted = Some(n) == self.cursor;
+            let marker = match selected {
+                Some(selected) if selected.contains(index) => FILLED_DOT,
+                Some(_) => OUTLINE_DOT,
+                None if highlighted => ARROW,
+                None => ' ',
+            };
+            let line = if let Some(reason) = &self.reasons[*index] {
+                formatted_disabled(term, marker, prompt, matches, reason)
+            } else if highlighted {
+                let line = format!(
+                    "{} {}",
+                    style(marker).red(),
+                    formatted_prompt(prompt, matches, true)
+                );
+                with_description(term, line, self.descriptions[*index].as_deref())
+            } else {
+                format!("{marker} {}", formatted_prompt(prompt, matches, false))
+            };
+            term.write_line(&line)?;
+        }
+        Ok(())
+    }
+
+    /// Ask for a single option getting its index as a result, space chooses the highlighted
+    /// option and enter skips when optional, otherwise enter chooses it
+    fn select(&mut self, term: &Term, title: &str, optional: bool) -> Result<Option<usize>> {
+        let actions: &[(&str, &str)] = if optional {
+            &[("space", "select"), ("enter", "skip")]
+        } else {
+            &[("enter", "select")]
+        };
+        let confirm = if optional { Key::Char(' ') } else { Key::Enter };
+        loop {
+            term.write_line(&formatted_question(title.to_string(), actions))?;
+            self.write(term, None)?;
+            let rendered = self.rendered_lines(term) + 1;
+
+            loop {
+                let key = term.read_key()?;
+                let (answer, index) = if key == confirm {
+                    let Some(index) = self.highlighted() else {
+                        continue;
+                    };
+                    let answer = match &self.answers {
+                        Some(answers) => answers[index].clone(),
+                        None => self.prompt(index).to_string(),
+                    };
+                    (answer, Some(index))
+                } else if key == Key::Enter {
+                    ("Skipped".to_string(), None)
+                } else {
+                    if self.handle_key(&key) {
+                        term.clear_last_lines(rendered)?;
+                        break;
+                    }
+                    continue;
+                };
+
+                term.clear_last_lines(rendered)?;
+                term.write_line(&formatted_answered_question(title.to_string(), answer))?;
+                return Ok(index);
+            }
+        }
+    }
+}
+
+/// Filter prompts to those fuzzy matching the query, keeping their index and matched characters
+fn filter_prompts<'a>(
+    query: &str,
+    prompts: impl IntoIterator<Item = &'a str>,
+) -> Vec<(usize, Vec<usize>)> {
+    prompts
+        .into_iter()
+        .enumerate()
+        .filter_map(|(n, prompt)| fuzzy_match(query, prompt).map(|matches| (n, matches)))
+        .collect()
+}
+
+fn fuzzy_match(query: &str, prompt: &str) -> Option<Vec<usize>> {
+    let mut query = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .peekable();
+    let mut matches = Vec::new();
+    for (n, c) in prompt.chars().enumerate() {
+        let Some(q) = query.peek() else {
+            break;
+        };
+        if c.to_lowercase().eq(std::iter::once(*q)) {
+            matches.push(n);
+            query.next();
+        }
+    }
+    query.peek().is_none().then_some(matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn move_cursor_wraps_around() {
+        assert_eq!(move_cursor(3, 4, true, |_| false), Some(0));
+        assert_eq!(move_cursor(0, 4, false, |_| false), Some(3));
+        assert_eq!(move_cursor(1, 4, true, |_| false), Some(2));
+    }
+
+    #[test]
+    fn move_cursor_skips_disabled() {
+        assert_eq!(move_cursor(0, 4, true, |n| n == 1 || n == 2), Some(3));
+        assert_eq!(move_cursor(0, 4, false, |n| n == 3), Some(2));
+    }
+
+    #[test]
+    fn move_cursor_all_disabled() {
+        assert_eq!(move_cursor(0, 3, true, |_| true), None);
+        assert_eq!(move_cursor(0, 0, false, |_| true), None);
+    }
+
+    #[test]
+    fn move_cursor_single_enabled() {
+        assert_eq!(move_cursor(2, 4, true, |n| n != 2), Some(2));
+        assert_eq!(move_cursor(0, 4, false, |n| n != 2), Some(2));
+    }
+
+    #[test]
+    fn first_enabled_skips_disabled() {
+        assert_eq!(first_enabled(3, |n| n == 0), Some(1));
+        assert_eq!(first_enabled(3, |_| true), None);
+    }
+}
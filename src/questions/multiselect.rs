@@ -1,7 +1,12 @@
-use crate::questions::{formatted_answered_question, formatted_question, FILLED_DOT, OUTLINE_DOT};
+use crate::questions::select::option_list;
+use crate::questions::{
+    formatted_answered_question, formatted_min_max_error, formatted_question, formatted_selected,
+    DisabledFunc,
+};
+use crate::Error::Other;
 use crate::Result;
 use crate::SelectEnum;
-use console::{style, Key, Term};
+use console::{Key, Term};
 
 /// Get multiple enum variants input from the user
 ///
@@ -24,14 +29,16 @@ use console::{style, Key, Term};
 /// # Ok(())
 /// # }
 /// ```
-pub struct MultiSelect<T: SelectEnum> {
+pub struct MultiSelect<'a, T: SelectEnum> {
     title: String,
     initial: Vec<T>,
     min: usize,
     max: usize,
+    filter: bool,
+    disabled: Option<Box<DisabledFunc<'a, T>>>,
 }
 
-impl<T: SelectEnum> MultiSelect<T> {
+impl<'a, T: SelectEnum> MultiSelect<'a, T> {
     /// Creates a select with the given title
     pub fn new(title: impl Into<String>) -> Self {
         Self {
@@ -39,6 +46,8 @@ impl<T: SelectEnum> MultiSelect<T> {
             initial: Vec::new(),
             min: 0,
             max: usize::MAX,
+            filter: false,
+            disabled: None,
         }
     }
 
@@ -60,144 +69,90 @@ impl<T: SelectEnum> MultiSelect<T> {
         self
     }
 
+    /// Set whether typing filters the variants, replacing the number key shortcuts
+    pub fn filter(mut self, filter: bool) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Disable variants, the function returns the reason a variant is disabled or none if it
+    /// can be selected
+    ///
+    /// Variants with fields aren't passed to the function, they can only be disabled with
+    /// `#[prompt(disabled = "Reason")]`
+    pub fn disabled(mut self, disabled: impl Fn(&T) -> Option<String> + 'a) -> Self {
+        self.disabled = Some(Box::new(disabled));
+        self
+    }
+
     /// Ask the question getting a list of the selected enum variants as a result
     pub fn ask(self, term: &Term) -> Result<Vec<T>> {
-        let mut cursor = 0;
-        let mut selected = self.initial;
+        let mut list = option_list(self.disabled.as_deref(), self.filter);
+        if list.enabled_len() == 0 {
+            return Err(Other("All variants are disabled"));
+        }
+        if list.enabled_len() < self.min {
+            return Err(Other("Fewer variants are enabled than the minimum"));
+        }
+        let mut selected: Vec<usize> = self.initial.iter().map(|v| v.to_index()).collect();
+        if selected.iter().any(|n| list.is_disabled(*n)) {
+            return Err(Other("An initial variant is disabled"));
+        }
         let mut active_err_msg = false;
         term.write_line(&formatted_question(
             self.title.clone(),
             &[("space", "select"), ("enter", "proceed")],
         ))?;
         loop {
-            Self::write_options(term, cursor, &selected)?;
+            list.write(term, Some(&selected))?;
+            let rendered = list.rendered_lines(term);
 
             loop {
                 let key = term.read_key()?;
                 let rerender = match key {
                     Key::Char(' ') => {
-                        let variant = T::from_index(cursor).unwrap();
-                        if let Some(n) = selected
-                            .iter()
-                            .position(|v| v.to_index() == variant.to_index())
-                        {
+                        let Some(index) = list.highlighted() else {
+                            continue;
+                        };
+                        if let Some(n) = selected.iter().position(|v| *v == index) {
                             selected.remove(n);
                         } else {
-                            selected.push(variant);
+                            selected.push(index);
                         }
                         true
                     }
                     Key::Enter => {
                         if selected.len() < self.min || selected.len() > self.max {
-                            Self::clear_render(term)?;
+                            term.clear_last_lines(rendered)?;
                             if active_err_msg {
                                 term.clear_last_lines(1)?;
                             }
-                            term.write_line(&format!(
-                                "{} {}",
-                                style('X').red(),
-                                style(if selected.len() < self.min {
-                                    format!("Must select at least {}", self.min)
-                                } else {
-                                    format!("Must select {} or less", self.max)
-                                })
-                                .red()
+                            term.write_line(&formatted_min_max_error(
+                                selected.len(),
+                                self.min,
+                                self.max,
                             ))?;
                             active_err_msg = true;
                             break;
                         }
-                        term.clear_last_lines(T::VARIANTS.len() + 1)?;
+                        term.clear_last_lines(rendered + if !active_err_msg { 1 } else { 2 })?;
                         term.write_line(&formatted_answered_question(
                             self.title.clone(),
-                            if !selected.is_empty() {
-                                selected
-                                    .iter()
-                                    .map(|v| v.prompt())
-                                    .collect::<Vec<_>>()
-                                    .join(", ")
-                            } else {
-                                "Skipped".to_string()
-                            },
+                            formatted_selected(&list, &selected),
                         ))?;
-                        return Ok(selected);
-                    }
-                    Key::ArrowUp => {
-                        if cursor > 0 {
-                            cursor -= 1
-                        } else {
-                            cursor = T::VARIANTS.len() - 1
-                        };
-                        true
-                    }
-                    Key::ArrowDown => {
-                        if cursor < T::VARIANTS.len() - 1 {
-                            cursor += 1
-                        } else {
-                            cursor = 0;
-                        };
-                        true
-                    }
-                    Key::Char(c @ '1'..='9') => {
-                        let index = c.to_digit(10).unwrap() as usize - 1;
-                        if index < T::VARIANTS.len() {
-                            cursor = index;
-                            true
-                        } else {
-                            false
-                        }
+                        return selected
+                            .iter()
+                            .map(|n| T::ask_variant(*n, term)?.ok_or(Other("Index out of range")))
+                            .collect();
                     }
-                    _ => false,
+                    key => list.handle_key(&key),
                 };
 
                 if rerender {
-                    Self::clear_render(term)?;
+                    term.clear_last_lines(rendered)?;
                     break;
                 }
             }
         }
     }
-
-    fn write_options(term: &Term, cursor: usize, selected: &[T]) -> Result<()> {
-        let (rows, _) = term.size();
-        let per_page = rows as usize - 2;
-
-        let page = page(term, cursor);
-        let start = page * per_page;
-        let end = start + per_page;
-
-        let selected_indices: Vec<usize> = selected.iter().map(|v| v.to_index()).collect();
-
-        for (n, variant) in T::VARIANTS.iter().enumerate().skip(start).take(per_page) {
-            if n >= end {
-                break;
-            }
-
-            let prompt = variant.prompt();
-            let dot = if selected_indices.contains(&n) {
-                FILLED_DOT
-            } else {
-                OUTLINE_DOT
-            };
-            let arm = if cursor == n {
-                format!("{} {}", style(dot).red(), style(prompt).red().bold())
-            } else {
-                format!("{dot} {}", prompt)
-            };
-            term.write_line(&arm)?;
-        }
-        Ok(())
-    }
-
-    fn clear_render(term: &Term) -> Result<()> {
-        let (_width, height) = term.size();
-        let per_page = height as usize;
-        term.clear_last_lines((T::VARIANTS.len()).min(per_page))?;
-        Ok(())
-    }
-}
-
-fn page(term: &Term, selected: usize) -> usize {
-    let (rows, _) = term.size();
-    let per_page = rows as usize - 2;
-    selected / per_page
 }
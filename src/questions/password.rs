@@ -0,0 +1,162 @@
+use crate::questions::input::{Echo, LineEditor};
+use crate::questions::{formatted_answered_question, formatted_question};
+use crate::Result;
+use console::{style, Key, StyledObject, Term};
+
+/// Get a secret input from the user without echoing it
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::Password;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let password = Password::new("Choose a password")
+///     .confirmation("Repeat the password")
+///     .reveal(true)
+///     .strength(true)
+///     .ask(&term)?;
+/// println!("Your password is {} characters long", password.len());
+/// # Ok(())
+/// # }
+/// ```
+pub struct Password {
+    title: String,
+    confirmation: Option<String>,
+    mask: char,
+    hidden: bool,
+    reveal: bool,
+    strength: bool,
+}
+
+impl Password {
+    /// Creates a password input with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            confirmation: None,
+            mask: '*',
+            hidden: false,
+            reveal: false,
+            strength: false,
+        }
+    }
+
+    /// Ask for the password a second time with the given title, both entries must match
+    pub fn confirmation(mut self, title: impl Into<String>) -> Self {
+        self.confirmation = Some(title.into());
+        self
+    }
+
+    /// Set the character shown in place of each typed character
+    pub fn mask(mut self, mask: char) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    /// Set whether nothing at all is shown while typing
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Set whether the input can be revealed by pressing tab
+    pub fn reveal(mut self, reveal: bool) -> Self {
+        self.reveal = reveal;
+        self
+    }
+
+    /// Set whether a strength indicator is shown while typing
+    pub fn strength(mut self, strength: bool) -> Self {
+        self.strength = strength;
+        self
+    }
+
+    /// Ask the question getting the inputted password as a result
+    pub fn ask(&self, term: &Term) -> Result<String> {
+        let mut err_msg = None;
+        loop {
+            let password = self.read(term, &self.title, err_msg.take())?;
+
+            if let Some(confirmation_title) = &self.confirmation {
+                let confirmation = self.read(term, confirmation_title, None)?;
+                if confirmation != password {
+                    err_msg = Some("The passwords don't match");
+                    continue;
+                }
+            }
+
+            term.write_line(&formatted_answered_question(
+                self.title.clone(),
+                String::from("[hidden]"),
+            ))?;
+            return Ok(password);
+        }
+    }
+
+    fn read(&self, term: &Term, title: &str, err_msg: Option<&str>) -> Result<String> {
+        let mut actions = vec![("enter", "proceed")];
+        if self.reveal {
+            actions.insert(0, ("tab", "reveal"));
+        }
+        term.write_line(&formatted_question(title.to_string(), &actions))?;
+        if let Some(msg) = err_msg {
+            term.write_line(&format!("{} {}", style('X').red(), style(msg).red()))?;
+        }
+
+        let mut editor = LineEditor::new("");
+        let mut revealed = false;
+
+        loop {
+            let echo = if revealed {
+                Echo::Plain
+            } else if self.hidden {
+                Echo::Hidden
+            } else {
+                Echo::Masked(self.mask)
+            };
+            term.clear_line()?;
+            editor.write(term, echo)?;
+            if self.strength && !editor.chars().is_empty() {
+                term.write_str(&format!("  {}", strength(editor.chars())))?;
+            }
+            editor.move_to_cursor(term, echo)?;
+
+            match term.read_key()? {
+                Key::Tab if self.reveal => revealed = !revealed,
+                Key::Enter => {
+                    term.clear_line()?;
+                    term.clear_last_lines(if err_msg.is_none() { 1 } else { 2 })?;
+                    return Ok(editor.text());
+                }
+                key => {
+                    editor.handle_key(&key);
+                }
+            }
+        }
+    }
+}
+
+fn strength(input: &[char]) -> StyledObject<&'static str> {
+    let classes = [
+        input.iter().any(|c| c.is_lowercase()),
+        input.iter().any(|c| c.is_uppercase()),
+        input.iter().any(|c| c.is_numeric()),
+        input.iter().any(|c| !c.is_alphanumeric()),
+    ];
+    let mut score = classes.iter().filter(|c| **c).count();
+    if input.len() >= 8 {
+        score += 1;
+    }
+    if input.len() >= 12 {
+        score += 1;
+    }
+
+    match score {
+        0..=2 => style("Weak").red(),
+        3 => style("Fair").yellow(),
+        4 => style("Good").cyan(),
+        _ => style("Strong").green(),
+    }
+}
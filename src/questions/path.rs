@@ -0,0 +1,335 @@
+use crate::{Input, Result, Suggester};
+use console::Term;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::result;
+
+/// Get a filesystem path input from the user, matching file and directory names
+/// are suggested while typing
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::PathInput;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let config = PathInput::new("Where is your config?")
+///     .must_exist(true)
+///     .extensions(["toml"])
+///     .ask(&term)?;
+/// println!("Loading {}", config.display());
+/// # Ok(())
+/// # }
+/// ```
+pub struct PathInput {
+    title: String,
+    default: Option<String>,
+    rules: PathRules,
+}
+
+#[derive(Clone, Default)]
+struct PathRules {
+    must_exist: bool,
+    must_not_exist: bool,
+    directories_only: bool,
+    extensions: Vec<String>,
+}
+
+impl PathInput {
+    /// Creates a path input with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            default: None,
+            rules: PathRules::default(),
+        }
+    }
+
+    /// Set the default value of the path
+    pub fn default(mut self, value: impl Into<String>) -> Self {
+        self.default = Some(value.into());
+        self
+    }
+
+    /// Set whether the path must already exist
+    pub fn must_exist(mut self, must_exist: bool) -> Self {
+        self.rules.must_exist = must_exist;
+        self
+    }
+
+    /// Set whether the path must not already exist
+    pub fn must_not_exist(mut self, must_not_exist: bool) -> Self {
+        self.rules.must_not_exist = must_not_exist;
+        self
+    }
+
+    /// Set whether only directories are accepted
+    pub fn directories_only(mut self, directories_only: bool) -> Self {
+        self.rules.directories_only = directories_only;
+        self
+    }
+
+    /// Set the file extensions accepted, such as `"toml"` or `"json"`
+    pub fn extensions(mut self, extensions: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.rules.extensions = extensions
+            .into_iter()
+            .map(|ext| ext.into().trim_start_matches('.').to_lowercase())
+            .collect();
+        self
+    }
+
+    /// Ask the question getting the inputted path as a result
+    pub fn ask(&self, term: &Term) -> Result<PathBuf> {
+        let validator_rules = self.rules.clone();
+        let mut input = Input::new(self.title.clone())
+            .validator(move |input| validator_rules.validator(input))
+            .suggester(self.rules.clone())
+            .display(|ans| ans.display().to_string());
+        if let Some(default) = &self.default {
+            input = input.default(default);
+        }
+        input.ask(term)
+    }
+}
+
+impl PathRules {
+    fn validator(&self, input: String) -> result::Result<PathBuf, String> {
+        if input.is_empty() {
+            return Err("You must enter a path".to_string());
+        }
+
+        let path = expand_home(&input);
+        if self.must_exist && !path.exists() {
+            return Err("Nothing exists at that path".to_string());
+        }
+        if self.must_not_exist && path.exists() {
+            return Err("Something already exists at that path".to_string());
+        }
+        if self.directories_only && path.exists() && !path.is_dir() {
+            return Err("Must be a directory".to_string());
+        }
+        if !self.directories_only && !self.has_extension(&path) {
+            return Err(format!(
+                "Must have one of the extensions: {}",
+                self.extensions.join(", ")
+            ));
+        }
+        Ok(path)
+    }
+
+    fn has_extension(&self, path: &Path) -> bool {
+        self.extensions.is_empty()
+            || path
+                .extension()
+                .and_then(OsStr::to_str)
+                .is_some_and(|ext| self.extensions.contains(&ext.to_lowercase()))
+    }
+}
+
+impl Suggester for PathRules {
+    fn suggest(&self, input: &str, _cursor: usize) -> Vec<String> {
+        let (dir, prefix) = match input.rfind(['/', std::path::MAIN_SEPARATOR]) {
+            Some(n) => input.split_at(n + 1),
+            None => ("", input),
+        };
+        let search_dir = if dir.is_empty() {
+            PathBuf::from(".")
+        } else {
+            expand_home(dir)
+        };
+        let Ok(entries) = fs::read_dir(search_dir) else {
+            return Vec::new();
+        };
+
+        let mut candidates: Vec<String> = entries
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                let name = entry.file_name().into_string().ok()?;
+                if !name.starts_with(prefix) || (name.starts_with('.') && !prefix.starts_with('.'))
+                {
+                    return None;
+                }
+                if entry.path().is_dir() {
+                    return Some(format!("{dir}{name}/"));
+                }
+                if self.directories_only || !self.has_extension(&entry.path()) {
+                    return None;
+                }
+                Some(format!("{dir}{name}"))
+            })
+            .collect();
+        candidates.sort();
+        candidates
+    }
+}
+
+fn expand_home(input: &str) -> PathBuf {
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"));
+    match (input.strip_prefix('~'), home) {
+        (Some(rest), Some(home)) if rest.is_empty() || rest.starts_with(['/', '\\']) => {
+            PathBuf::from(home).join(rest.trim_start_matches(['/', '\\']))
+        }
+        _ => PathBuf::from(input),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A scratch directory holding `config.toml`, `notes.TXT`, `.hidden.toml`
+    /// and `configs/`, removed again when dropped
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("quizzard-{name}-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(dir.join("configs")).unwrap();
+            for file in ["config.toml", "notes.TXT", ".hidden.toml"] {
+                fs::write(dir.join(file), "").unwrap();
+            }
+            Self(dir)
+        }
+
+        fn path(&self, name: &str) -> String {
+            format!("{}/{name}", self.0.display())
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn rules(must_exist: bool, must_not_exist: bool, directories_only: bool) -> PathRules {
+        PathRules {
+            must_exist,
+            must_not_exist,
+            directories_only,
+            extensions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn checks_existence() {
+        let dir = TempDir::new("existence");
+        let cases = [
+            (rules(true, false, false), "config.toml", None),
+            (
+                rules(true, false, false),
+                "missing.toml",
+                Some("Nothing exists at that path"),
+            ),
+            (rules(false, true, false), "missing.toml", None),
+            (
+                rules(false, true, false),
+                "config.toml",
+                Some("Something already exists at that path"),
+            ),
+            (rules(false, false, true), "configs", None),
+            (rules(false, false, true), "missing", None),
+            (
+                rules(false, false, true),
+                "config.toml",
+                Some("Must be a directory"),
+            ),
+            (
+                rules(true, false, true),
+                "missing",
+                Some("Nothing exists at that path"),
+            ),
+        ];
+        for (rules, name, err) in cases {
+            let input = dir.path(name);
+            assert_eq!(
+                rules.validator(input.clone()),
+                match err {
+                    Some(err) => Err(err.to_string()),
+                    None => Ok(PathBuf::from(&input)),
+                },
+                "{input}"
+            );
+        }
+        assert_eq!(
+            rules(false, false, false).validator(String::new()),
+            Err("You must enter a path".to_string())
+        );
+    }
+
+    #[test]
+    fn checks_extensions() {
+        let rules = PathRules {
+            extensions: vec!["toml".to_string(), "txt".to_string()],
+            ..PathRules::default()
+        };
+        let cases = [
+            ("config.toml", true),
+            ("notes.TXT", true),
+            ("archive.tar.toml", true),
+            ("config.json", false),
+            ("toml", false),
+            ("config", false),
+        ];
+        for (input, accepted) in cases {
+            let result = rules.validator(input.to_string());
+            if accepted {
+                assert_eq!(result, Ok(PathBuf::from(input)), "{input}");
+            } else {
+                assert_eq!(
+                    result,
+                    Err("Must have one of the extensions: toml, txt".to_string()),
+                    "{input}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn suggests_matching_entries() {
+        let dir = TempDir::new("suggest");
+        let toml_only = PathRules {
+            extensions: vec!["toml".to_string()],
+            ..PathRules::default()
+        };
+        let cases = [
+            (
+                rules(false, false, false),
+                "conf",
+                vec!["config.toml", "configs/"],
+            ),
+            (
+                rules(false, false, false),
+                "",
+                vec!["config.toml", "configs/", "notes.TXT"],
+            ),
+            (rules(false, false, false), ".", vec![".hidden.toml"]),
+            (rules(false, false, false), "x", vec![]),
+            (rules(false, false, true), "", vec!["configs/"]),
+            (toml_only, "", vec!["config.toml", "configs/"]),
+        ];
+        for (rules, prefix, expected) in cases {
+            let input = dir.path(prefix);
+            let expected: Vec<String> = expected.iter().map(|name| dir.path(name)).collect();
+            assert_eq!(rules.suggest(&input, input.len()), expected, "{input}");
+        }
+        assert!(rules(false, false, false)
+            .suggest(&dir.path("missing/"), 0)
+            .is_empty());
+    }
+
+    #[test]
+    fn expands_the_home_directory() {
+        let Some(home) = std::env::var_os("HOME") else {
+            return;
+        };
+        let home = PathBuf::from(home);
+        assert_eq!(expand_home("~"), home);
+        assert_eq!(expand_home("~/notes"), home.join("notes"));
+        assert_eq!(expand_home("~notes"), PathBuf::from("~notes"));
+        assert_eq!(expand_home("notes/~"), PathBuf::from("notes/~"));
+    }
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "net")]
+
+use crate::{Input, Result};
+use console::Term;
+use std::num::IntErrorKind;
+use std::result;
+
+/// Get a port number input from the user
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::Port;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let port = Port::new("Which port should the server listen on?")
+///     .privileged(false)
+///     .ask(&term)?;
+/// println!("Listening on {port}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct Port {
+    title: String,
+    privileged: bool,
+}
+
+impl Port {
+    /// Creates a port input with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            privileged: true,
+        }
+    }
+
+    /// Set whether the privileged ports below 1024 are accepted
+    pub fn privileged(mut self, privileged: bool) -> Self {
+        self.privileged = privileged;
+        self
+    }
+
+    /// Ask the question getting the inputted port as a result
+    pub fn ask(&self, term: &Term) -> Result<u16> {
+        let privileged = self.privileged;
+        Input::new(self.title.clone())
+            .charset('0'..='9')
+            .validator(move |input| {
+                let port = parse_port(&input)?;
+                if !privileged && port < 1024 {
+                    return Err("Ports below 1024 are privileged and can't be used".to_string());
+                }
+                Ok(port)
+            })
+            .ask(term)
+    }
+}
+
+pub(crate) fn parse_port(input: &str) -> result::Result<u16, String> {
+    input.parse::<u16>().map_err(|err| {
+        match err.kind() {
+            IntErrorKind::Empty => "You must enter a port",
+            IntErrorKind::InvalidDigit => "Ports can only contain digits",
+            IntErrorKind::PosOverflow => "The port must be between 0 and 65535",
+            _ => "Invalid port",
+        }
+        .to_string()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ports() {
+        let cases = [("0", 0), ("80", 80), ("08080", 8080), ("65535", 65535)];
+        for (input, port) in cases {
+            assert_eq!(parse_port(input), Ok(port), "{input}");
+        }
+    }
+
+    #[test]
+    fn explains_invalid_ports() {
+        let cases = [
+            ("", "You must enter a port"),
+            ("80a", "Ports can only contain digits"),
+            ("-1", "Ports can only contain digits"),
+            ("65536", "The port must be between 0 and 65535"),
+            ("99999999999", "The port must be between 0 and 65535"),
+        ];
+        for (input, err) in cases {
+            assert_eq!(parse_port(input), Err(err.to_string()), "{input}");
+        }
+    }
+}
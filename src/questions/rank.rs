@@ -0,0 +1,172 @@
+use crate::questions::{
+    formatted_answered_question, formatted_question, page, page_len, per_page, ARROW,
+};
+use crate::Error::Other;
+use crate::Result;
+use crate::SelectEnum;
+use console::{style, Key, Term};
+use std::marker::PhantomData;
+
+const GRABBED: char = '↕';
+
+/// Get the enum variants ordered by the user
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::{Rank, SelectEnum};
+///
+/// #[derive(SelectEnum, Debug)]
+/// enum Feature {
+///     Speed,
+///     Stability,
+///     Documentation,
+/// }
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let ranking = Rank::<Feature>::new("What should we focus on?").ask(&term)?;
+/// println!("Your priorities are {ranking:?}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct Rank<T: SelectEnum> {
+    title: String,
+    top: Option<usize>,
+    data: PhantomData<T>,
+}
+
+impl<T: SelectEnum> Rank<T> {
+    /// Creates a rank with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            top: None,
+            data: PhantomData::<T>,
+        }
+    }
+
+    /// Only rank the given number of variants, the rest are left out of the result
+    pub fn top(mut self, top: usize) -> Self {
+        self.top = Some(top);
+        self
+    }
+
+    /// Ask the question getting the ranked enum variants as a result
+    pub fn ask(&self, term: &Term) -> Result<Vec<T>> {
+        let ranked = self.top.unwrap_or(usize::MAX).min(T::variant_count());
+        let mut order: Vec<usize> = (0..T::variant_count()).collect();
+        let mut cursor = 0;
+        let mut grabbed = false;
+
+        loop {
+            term.write_line(&formatted_question(
+                self.title.clone(),
+                &[
+                    ("space", if grabbed { "drop" } else { "grab" }),
+                    ("enter", "proceed"),
+                ],
+            ))?;
+            Self::write_options(term, &order, cursor, grabbed, ranked)?;
+            let rendered = page_len(term, cursor, order.len()) + 1;
+
+            loop {
+                let key = term.read_key()?;
+                let rerender = match key {
+                    Key::Char(' ') => {
+                        grabbed = !grabbed;
+                        true
+                    }
+                    Key::Enter => {
+                        term.clear_last_lines(rendered)?;
+                        term.write_line(&formatted_answered_question(
+                            self.title.clone(),
+                            order
+                                .iter()
+                                .take(ranked)
+                                .map(|n| T::prompt_at(*n))
+                                .collect::<Vec<_>>()
+                                .join(", "),
+                        ))?;
+                        return order
+                            .iter()
+                            .take(ranked)
+                            .map(|n| T::ask_variant(*n, term)?.ok_or(Other("Index out of range")))
+                            .collect();
+                    }
+                    Key::ArrowUp if grabbed => {
+                        if cursor > 0 {
+                            order.swap(cursor, cursor - 1);
+                            cursor -= 1;
+                        }
+                        true
+                    }
+                    Key::ArrowDown if grabbed => {
+                        if cursor < order.len() - 1 {
+                            order.swap(cursor, cursor + 1);
+                            cursor += 1;
+                        }
+                        true
+                    }
+                    Key::ArrowUp => {
+                        if cursor > 0 {
+                            cursor -= 1
+                        } else {
+                            cursor = order.len() - 1
+                        };
+                        true
+                    }
+                    Key::ArrowDown => {
+                        if cursor < order.len() - 1 {
+                            cursor += 1
+                        } else {
+                            cursor = 0
+                        };
+                        true
+                    }
+                    _ => false,
+                };
+
+                if rerender {
+                    term.clear_last_lines(rendered)?;
+                    break;
+                }
+            }
+        }
+    }
+
+    fn write_options(
+        term: &Term,
+        order: &[usize],
+        cursor: usize,
+        grabbed: bool,
+        ranked: usize,
+    ) -> Result<()> {
+        let per_page = per_page(term);
+        let page = page(term, cursor);
+        let start = page * per_page;
+
+        for (n, index) in order.iter().enumerate().skip(start).take(per_page) {
+            let prompt = T::prompt_at(*index);
+            let position = if n < ranked {
+                format!("{}.", n + 1)
+            } else {
+                String::from("-")
+            };
+            let arm = if n == cursor {
+                let marker = if grabbed { GRABBED } else { ARROW };
+                format!(
+                    "{} {position} {}",
+                    style(marker).red(),
+                    style(prompt).red().bold()
+                )
+            } else if n < ranked {
+                format!("  {position} {prompt}")
+            } else {
+                format!("  {}", style(format!("{position} {prompt}")).dim())
+            };
+            term.write_line(&arm)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+use crate::questions::{formatted_answered_question, formatted_question};
+use crate::Error::Other;
+use crate::Result;
+use console::{style, Key, Term};
+
+const FILLED_STAR: char = '★';
+const OUTLINE_STAR: char = '☆';
+
+/// Get a rating on a scale such as 1-5 stars or 0-10 from the user
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::Rating;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let stars = Rating::new("How was your stay?").stars(true).ask(&term)?;
+/// let score = Rating::nps("How likely are you to recommend us?").ask_opt(&term)?;
+/// println!("You gave us {stars} stars and a score of {score:?}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct Rating {
+    title: String,
+    min: u8,
+    max: u8,
+    stars: bool,
+    labels: Option<(String, String)>,
+}
+
+impl Rating {
+    /// Creates a rating from 1 to 5 with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            min: 1,
+            max: 5,
+            stars: false,
+            labels: None,
+        }
+    }
+
+    /// Creates a net promoter score rating from 0 to 10 with the given title
+    pub fn nps(title: impl Into<String>) -> Self {
+        Self::new(title)
+            .min(0)
+            .max(10)
+            .labels("Not at all likely", "Extremely likely")
+    }
+
+    /// Sets the lowest rating
+    pub fn min(mut self, min: u8) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the highest rating
+    pub fn max(mut self, max: u8) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set whether the rating is shown as stars instead of numbers
+    pub fn stars(mut self, stars: bool) -> Self {
+        self.stars = stars;
+        self
+    }
+
+    /// Set the labels shown next to the lowest and highest ratings
+    pub fn labels(mut self, low: impl Into<String>, high: impl Into<String>) -> Self {
+        self.labels = Some((low.into(), high.into()));
+        self
+    }
+
+    /// Ask the question getting the rating as a result
+    pub fn ask(&self, term: &Term) -> Result<u8> {
+        self.run(term, false)?.ok_or(Other("No rating was given"))
+    }
+
+    /// Ask the question with a not applicable option getting either the rating or none as a result
+    pub fn ask_opt(&self, term: &Term) -> Result<Option<u8>> {
+        self.run(term, true)
+    }
+
+    fn run(&self, term: &Term, optional: bool) -> Result<Option<u8>> {
+        let max = self.max.max(self.min);
+        let mut actions = vec![("0-9", "rate"), ("enter", "proceed")];
+        if optional {
+            actions.insert(1, ("n", "skip"));
+        }
+        term.write_line(&formatted_question(self.title.clone(), &actions))?;
+
+        // None is no rating yet while Some(None) is not applicable
+        let mut selected: Option<Option<u8>> = None;
+        let mut typed = String::new();
+
+        loop {
+            term.clear_line()?;
+            term.write_str(&self.formatted_rating(selected, optional))?;
+
+            let key = term.read_key()?;
+            match key {
+                Key::ArrowLeft => {
+                    selected = Some(match selected {
+                        None => Some(self.min),
+                        Some(None) => Some(max),
+                        Some(Some(n)) => Some(n.saturating_sub(1).max(self.min)),
+                    })
+                }
+                Key::ArrowRight => {
+                    selected = match selected {
+                        None => Some(Some(self.min)),
+                        Some(Some(n)) if n < max => Some(Some(n + 1)),
+                        Some(Some(_)) if optional => Some(None),
+                        other => other,
+                    }
+                }
+                Key::Char(c @ '0'..='9') => {
+                    typed.push(c);
+                    let rating = match typed.parse::<u8>() {
+                        Ok(n) if n >= self.min && n <= max => Some(n),
+                        _ => {
+                            typed = c.to_string();
+                            typed
+                                .parse::<u8>()
+                                .ok()
+                                .filter(|n| *n >= self.min && *n <= max)
+                        }
+                    };
+                    if let Some(rating) = rating {
+                        selected = Some(Some(rating));
+                    }
+                    continue;
+                }
+                Key::Char('n' | 'N') if optional => selected = Some(None),
+                Key::Enter => {
+                    if let Some(ans) = selected {
+                        term.clear_line()?;
+                        term.clear_last_lines(1)?;
+                        term.write_line(&formatted_answered_question(
+                            self.title.clone(),
+                            match ans {
+                                Some(n) => format!("{n}/{max}"),
+                                None => String::from("N/A"),
+                            },
+                        ))?;
+                        return Ok(ans);
+                    }
+                }
+                _ => {}
+            }
+            typed.clear();
+        }
+    }
+
+    fn formatted_rating(&self, selected: Option<Option<u8>>, optional: bool) -> String {
+        let max = self.max.max(self.min);
+        let cells = (self.min..=max)
+            .map(|n| {
+                if self.stars {
+                    match selected {
+                        Some(Some(s)) if n <= s => style(FILLED_STAR).red().bold().to_string(),
+                        _ => OUTLINE_STAR.to_string(),
+                    }
+                } else if selected == Some(Some(n)) {
+                    style(format!(" {n} ")).red().bold().reverse().to_string()
+                } else {
+                    format!(" {n} ")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(if self.stars { " " } else { "" });
+
+        let mut line = match &self.labels {
+            Some((low, high)) => format!("{} {cells} {}", style(low).dim(), style(high).dim()),
+            None => cells,
+        };
+        if optional {
+            let na = if selected == Some(None) {
+                style(" N/A ").red().bold().reverse().to_string()
+            } else {
+                String::from(" N/A ")
+            };
+            line.push_str(&format!("  {na}"));
+        }
+        line
+    }
+}
@@ -1,13 +1,133 @@
-use super::{formatted_answered_question, formatted_question, ARROW};
+use super::OptionList;
 use crate::Error::Other;
 use crate::Result;
-use console::{style, Key, Term};
+use console::Term;
 
+/// An enum which can be selected from, usually derived
+///
+/// Variants with fields ask a question for each field once selected, a field's title
+/// is set with `#[prompt("Title")]` in the same way as the fields of a [`Form`](crate::Form)
+///
+/// A variant's description is set with `#[prompt("Prompt", description = "Description")]`,
+/// falling back to its doc comment, and is shown beside the variant when it is highlighted.
+/// Variants set with `#[prompt(disabled = "Reason")]` are shown with the reason and can't be
+/// selected. Variants can also be disabled while asking with [`Select::disabled`] and
+/// [`MultiSelect::disabled`](crate::MultiSelect::disabled), but only variants without fields
+/// are passed to the function as the others can't be created without asking for their fields
+///
+/// Implementing it by hand only needs `VARIANTS`, `prompt`, `to_index` and `from_index`
+/// for enums without fields. Enums with fields set `PROMPTS` instead of `VARIANTS`,
+/// return none from `from_index` for those variants and ask for them in `ask_variant`
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::{Select, SelectEnum};
+///
+/// #[derive(Clone, Copy)]
+/// enum Speed {
+///     Slow,
+///     Fast,
+/// }
+///
+/// impl SelectEnum for Speed {
+///     const VARIANTS: &'static [Self] = &[Self::Slow, Self::Fast];
+///     fn prompt(&self) -> &'static str {
+///         match self {
+///             Self::Slow => "Slow",
+///             Self::Fast => "Fast",
+///         }
+///     }
+///     fn to_index(&self) -> usize {
+///         *self as usize
+///     }
+///     fn from_index(n: usize) -> Option<Self> {
+///         Self::VARIANTS.get(n).copied()
+///     }
+/// }
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let speed = Select::<Speed>::new("How fast is your code?").ask(&term)?;
+/// # Ok(())
+/// # }
+/// ```
 pub trait SelectEnum: Sized + 'static {
-    const VARIANTS: &'static [Self];
+    /// Every variant in order, which is empty for derived enums with variants that have fields
+    const VARIANTS: &'static [Self] = &[];
+    /// The prompt of each variant in order, which is taken from `VARIANTS` if empty
+    const PROMPTS: &'static [&'static str] = &[];
+    /// The description of each variant, which is empty if no variant has one
+    const DESCRIPTIONS: &'static [Option<&'static str>] = &[];
+    /// The reason each variant is disabled, which is empty if no variant is disabled
+    const DISABLED: &'static [Option<&'static str>] = &[];
     fn prompt(&self) -> &'static str;
     fn to_index(&self) -> usize;
+    /// Get the variant at the index, which is none for variants with fields
     fn from_index(n: usize) -> Option<Self>;
+    /// Get the variant at the index, asking the user for any fields it has
+    fn ask_variant(n: usize, _term: &Term) -> Result<Option<Self>> {
+        Ok(Self::from_index(n))
+    }
+    /// The number of variants
+    fn variant_count() -> usize {
+        if Self::PROMPTS.is_empty() {
+            Self::VARIANTS.len()
+        } else {
+            Self::PROMPTS.len()
+        }
+    }
+    /// Get the prompt of the variant at the index
+    fn prompt_at(n: usize) -> &'static str {
+        match Self::PROMPTS.get(n) {
+            Some(prompt) => prompt,
+            None => Self::VARIANTS[n].prompt(),
+        }
+    }
+    /// Get the description of the variant at the index
+    fn description(n: usize) -> Option<&'static str> {
+        Self::DESCRIPTIONS.get(n).copied().flatten()
+    }
+    /// Get the reason the variant at the index is disabled
+    fn disabled_reason(n: usize) -> Option<&'static str> {
+        Self::DISABLED.get(n).copied().flatten()
+    }
+}
+
+/// The prompt of each variant in order
+pub(crate) fn prompts<T: SelectEnum>() -> impl Iterator<Item = &'static str> {
+    (0..T::variant_count()).map(T::prompt_at)
+}
+
+pub(crate) type DisabledFunc<'a, T> = dyn Fn(&T) -> Option<String> + 'a;
+
+/// The reason each variant is disabled, either by its attribute or the function given
+pub(crate) fn disabled_reasons<T: SelectEnum>(
+    disabled: Option<&DisabledFunc<'_, T>>,
+) -> Vec<Option<String>> {
+    (0..T::variant_count())
+        .map(|n| {
+            T::disabled_reason(n).map(String::from).or_else(|| {
+                let disabled = disabled?;
+                disabled(&T::from_index(n)?)
+            })
+        })
+        .collect()
+}
+
+/// The options of a select over the variants of an enum
+pub(super) fn option_list<T: SelectEnum>(
+    disabled: Option<&DisabledFunc<'_, T>>,
+    filter: bool,
+) -> OptionList {
+    OptionList::new(
+        prompts::<T>().map(String::from).collect(),
+        (0..T::variant_count())
+            .map(|n| T::description(n).map(String::from))
+            .collect(),
+        disabled_reasons(disabled),
+        filter,
+    )
 }
 
 /// Get a single enum variant input from the user
@@ -21,6 +141,7 @@ pub trait SelectEnum: Sized + 'static {
 /// enum Speed {
 ///     Slow,
 ///     Medium,
+///     #[prompt("Fast", description = "Uses more memory")]
 ///     Fast,
 /// }
 ///
@@ -28,21 +149,36 @@ pub trait SelectEnum: Sized + 'static {
 /// let term = Term::stdout();
 /// let answer = Select::<Speed>::new("How fast is your code?").ask(&term)?;
 /// println!("You answered Speed::{answer:?}");
+///
+/// // Filtering lets the user type to narrow down long lists
+/// let answer = Select::<Speed>::new("How fast is your code?")
+///     .filter(true)
+///     .ask(&term)?;
+///
+/// // Variants can be disabled while asking, giving the reason why
+/// let slowest = Speed::Medium.to_index();
+/// let answer = Select::<Speed>::new("How fast is your code?")
+///     .disabled(|speed| (speed.to_index() < slowest).then(|| "Too slow".to_string()))
+///     .ask(&term)?;
 /// # Ok(())
 /// # }
 /// ```
 #[derive(Default)]
-pub struct Select<T: SelectEnum> {
+pub struct Select<'a, T: SelectEnum> {
     title: String,
     initial: Option<T>,
+    filter: bool,
+    disabled: Option<Box<DisabledFunc<'a, T>>>,
 }
 
-impl<T: SelectEnum> Select<T> {
+impl<'a, T: SelectEnum> Select<'a, T> {
     /// Creates a select with the given title
     pub fn new(title: impl Into<String>) -> Self {
         Self {
             title: title.into(),
             initial: None,
+            filter: false,
+            disabled: None,
         }
     }
 
@@ -52,167 +188,117 @@ impl<T: SelectEnum> Select<T> {
         self
     }
 
+    /// Set whether typing filters the variants, replacing the number key shortcuts
+    pub fn filter(mut self, filter: bool) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Disable variants, the function returns the reason a variant is disabled or none if it
+    /// can be selected
+    ///
+    /// Variants with fields aren't passed to the function, they can only be disabled with
+    /// `#[prompt(disabled = "Reason")]`
+    pub fn disabled(mut self, disabled: impl Fn(&T) -> Option<String> + 'a) -> Self {
+        self.disabled = Some(Box::new(disabled));
+        self
+    }
+
     /// Ask the question getting the selected enum variant as a result
     pub fn ask(&self, term: &Term) -> Result<T> {
-        let mut selected = self
-            .initial
-            .as_ref()
-            .map(|v| v.to_index())
-            .unwrap_or_default();
-        loop {
-            term.write_line(&formatted_question(
-                self.title.clone(),
-                &[("enter", "select")],
-            ))?;
-
-            Self::write_options(term, selected)?;
-
-            loop {
-                let key = term.read_key()?;
-                let rerender = match key {
-                    Key::Enter => {
-                        term.clear_last_lines(T::VARIANTS.len() + 1)?;
-                        let ans = T::from_index(selected).ok_or(Other("Index out of range"))?;
-                        term.write_line(&formatted_answered_question(
-                            self.title.clone(),
-                            ans.prompt().to_string(),
-                        ))?;
-                        return Ok(ans);
-                    }
-                    Key::ArrowUp => {
-                        if selected > 0 {
-                            selected -= 1
-                        } else {
-                            selected = T::VARIANTS.len() - 1
-                        };
-                        true
-                    }
-                    Key::ArrowDown => {
-                        if selected < T::VARIANTS.len() - 1 {
-                            selected += 1
-                        } else {
-                            selected = 0
-                        };
-                        true
-                    }
-                    Key::Char(c @ '1'..='9') => {
-                        let index = c.to_digit(10).unwrap() as usize - 1;
-                        if index < T::VARIANTS.len() {
-                            selected = index;
-                            true
-                        } else {
-                            false
-                        }
-                    }
-                    _ => false,
-                };
-
-                if rerender {
-                    let (_width, height) = term.size();
-                    let per_page = height as usize - 2;
-                    term.clear_last_lines((T::VARIANTS.len() + 1).min(per_page))?;
-                    break;
-                }
-            }
-        }
+        self.run(term, false)?
+            .ok_or(Other("No variant was selected"))
     }
 
     /// Ask the question optionally getting either the selected enum variant or none as a result
     pub fn ask_opt(&self, term: &Term) -> Result<Option<T>> {
-        let mut selected = 0;
-        loop {
-            term.write_line(&formatted_question(
-                self.title.clone(),
-                &[("space", "select"), ("enter", "skip")],
-            ))?;
-
-            Self::write_options(term, selected)?;
-
-            loop {
-                let key = term.read_key()?;
-                let rerender = match key {
-                    Key::Char(' ') => {
-                        term.clear_last_lines(T::VARIANTS.len() + 1)?;
-                        let ans = T::from_index(selected).ok_or(Other("Index out of range"))?;
-                        term.write_line(&formatted_answered_question(
-                            self.title.clone(),
-                            ans.prompt().to_string(),
-                        ))?;
-                        return Ok(Some(ans));
-                    }
-                    Key::Enter => {
-                        term.clear_last_lines(T::VARIANTS.len() + 1)?;
-                        term.write_line(&formatted_answered_question(
-                            self.title.clone(),
-                            String::from("Skipped"),
-                        ))?;
-                        return Ok(None);
-                    }
-                    Key::ArrowUp => {
-                        if selected > 0 {
-                            selected -= 1
-                        } else {
-                            selected = T::VARIANTS.len() - 1
-                        };
-                        true
-                    }
-                    Key::ArrowDown => {
-                        if selected < T::VARIANTS.len() - 1 {
-                            selected += 1
-                        } else {
-                            selected = 0
-                        };
-                        true
-                    }
-                    Key::Char(c @ '1'..='9') => {
-                        let index = c.to_digit(10).unwrap() as usize - 1;
-                        if index < T::VARIANTS.len() {
-                            selected = index;
-                            true
-                        } else {
-                            false
-                        }
-                    }
-                    _ => false,
-                };
-
-                if rerender {
-                    let (_width, height) = term.size();
-                    let per_page = height as usize - 2;
-                    term.clear_last_lines((T::VARIANTS.len() + 1).min(per_page))?;
-                    break;
-                }
+        self.run(term, true)
+    }
+
+    fn run(&self, term: &Term, optional: bool) -> Result<Option<T>> {
+        let mut list = option_list(self.disabled.as_deref(), self.filter);
+        if list.enabled_len() == 0 {
+            return Err(Other("All variants are disabled"));
+        }
+        if let Some(initial) = &self.initial {
+            if list.is_disabled(initial.to_index()) {
+                return Err(Other("The initial variant is disabled"));
             }
+            list.set_cursor(initial.to_index());
+        }
+        match list.select(term, &self.title, optional)? {
+            Some(index) => T::ask_variant(index, term)?
+                .ok_or(Other("Index out of range"))
+                .map(Some),
+            None => Ok(None),
         }
     }
+}
 
-    fn write_options(term: &Term, selected: usize) -> Result<()> {
-        let (rows, _) = term.size();
-        let per_page = rows as usize - 2;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let page = page(term, selected);
-        let start = page * per_page;
-        let end = start + per_page;
+    enum Database {
+        Sqlite,
+        Postgres,
+        #[allow(dead_code)]
+        Custom(String),
+    }
 
-        for (n, variant) in T::VARIANTS.iter().enumerate().skip(start).take(per_page) {
-            if n >= end {
-                break;
+    impl SelectEnum for Database {
+        const PROMPTS: &'static [&'static str] = &["SQLite", "Postgres", "Custom"];
+        const DISABLED: &'static [Option<&'static str>] =
+            &[None, Some("Requires the `pg` feature"), None];
+        fn prompt(&self) -> &'static str {
+            Self::PROMPTS[self.to_index()]
+        }
+        fn to_index(&self) -> usize {
+            match self {
+                Self::Sqlite => 0,
+                Self::Postgres => 1,
+                Self::Custom(_) => 2,
+            }
+        }
+        fn from_index(n: usize) -> Option<Self> {
+            match n {
+                0 => Some(Self::Sqlite),
+                1 => Some(Self::Postgres),
+                _ => None,
             }
-
-            let prompt = variant.prompt();
-            let arm = if n == selected {
-                format!("{} {}", style(ARROW).red(), style(prompt).red().bold())
-            } else {
-                format!("  {}", prompt)
-            };
-            term.write_line(&arm)?;
         }
-        Ok(())
     }
-}
 
-fn page(term: &Term, selected: usize) -> usize {
-    let (rows, _) = term.size();
-    let per_page = rows as usize - 2;
-    selected / per_page
+    #[test]
+    fn disabled_reasons_from_the_attribute() {
+        assert_eq!(
+            disabled_reasons::<Database>(None),
+            [None, Some("Requires the `pg` feature".to_string()), None]
+        );
+    }
+
+    #[test]
+    fn disabled_reasons_from_the_function() {
+        let installed = ["SQLite"];
+        let disabled = |database: &Database| {
+            (!installed.contains(&database.prompt())).then(|| "Not installed".to_string())
+        };
+        // The attribute takes priority and variants with fields aren't passed to the function
+        assert_eq!(
+            disabled_reasons::<Database>(Some(&disabled)),
+            [None, Some("Requires the `pg` feature".to_string()), None]
+        );
+
+        let disabled = |database: &Database| {
+            matches!(database, Database::Sqlite).then(|| "Too simple".to_string())
+        };
+        assert_eq!(
+            disabled_reasons::<Database>(Some(&disabled)),
+            [
+                Some("Too simple".to_string()),
+                Some("Requires the `pg` feature".to_string()),
+                None
+            ]
+        );
+    }
 }
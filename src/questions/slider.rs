@@ -0,0 +1,239 @@
+use crate::questions::{formatted_answered_question, formatted_question, ARROW};
+use crate::Error::Other;
+use crate::{IntError, Integer, Result};
+use console::{style, Key, Term};
+use num_traits::PrimInt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+const WIDTH: usize = 30;
+
+/// Get an integer input from the user by sliding between a min and a max
+///
+/// The left and right arrow keys move by the step, page up and page down move by the
+/// page step and a number can be typed directly
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::Slider;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let volume = Slider::<u8>::new("How loud?")
+///     .max(100)
+///     .step(5)
+///     .ask(&term)?;
+/// println!("Setting the volume to {volume}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct Slider<T: PrimInt + FromStr + Display + 'static>
+where
+    T::Err: IntError,
+{
+    title: String,
+    initial: Option<T>,
+    min: T,
+    max: T,
+    step: T,
+    page_step: Option<T>,
+}
+
+impl<T: PrimInt + FromStr + Display + 'static> Slider<T>
+where
+    T::Err: IntError,
+{
+    /// Creates a slider with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            initial: None,
+            min: T::min_value(),
+            max: T::max_value(),
+            step: T::one(),
+            page_step: None,
+        }
+    }
+
+    /// Set the initial value, defaults to the min value
+    pub fn initial(mut self, initial: T) -> Self {
+        self.initial = Some(initial);
+        self
+    }
+
+    /// Sets the min value accepted by the slider
+    pub fn min(mut self, min: T) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the max value accepted by the slider
+    pub fn max(mut self, max: T) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets how much the left and right arrow keys change the value
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step.max(T::one());
+        self
+    }
+
+    /// Sets how much page up and page down change the value, defaults to a tenth of the range
+    pub fn page_step(mut self, page_step: T) -> Self {
+        self.page_step = Some(page_step.max(T::one()));
+        self
+    }
+
+    /// Ask the question getting the chosen integer as a result
+    pub fn ask(&self, term: &Term) -> Result<T> {
+        let mut value = self.initial_value()?;
+        term.write_line(&formatted_question(
+            self.title.clone(),
+            &[("left/right", "slide"), ("enter", "proceed")],
+        ))?;
+
+        let page_step = self.page_step.unwrap_or_else(|| self.default_page_step());
+        let mut typed = String::new();
+        let mut active_err_msg = false;
+
+        loop {
+            term.clear_line()?;
+            term.write_str(&self.formatted_slider(value, &typed))?;
+
+            let key = term.read_key()?;
+            match key {
+                Key::ArrowLeft => value = self.decrease(value, self.step),
+                Key::ArrowRight => value = self.increase(value, self.step),
+                Key::PageDown => value = self.decrease(value, page_step),
+                Key::PageUp => value = self.increase(value, page_step),
+                Key::Home => value = self.min,
+                Key::End => value = self.max,
+                Key::Char(c) if c.is_ascii_digit() || (c == '-' && self.min < T::zero()) => {
+                    typed.push(c);
+                    if let Ok(typed_value) = Integer::validator(typed.clone(), self.min, self.max) {
+                        value = typed_value;
+                    }
+                    continue;
+                }
+                Key::Backspace if !typed.is_empty() => {
+                    typed.pop();
+                    continue;
+                }
+                Key::Enter => {
+                    let validated = if typed.is_empty() {
+                        Ok(value)
+                    } else {
+                        Integer::validator(typed.clone(), self.min, self.max)
+                    };
+                    match validated {
+                        Ok(ans) => {
+                            term.clear_line()?;
+                            term.clear_last_lines(if !active_err_msg { 1 } else { 2 })?;
+                            term.write_line(&formatted_answered_question(
+                                self.title.clone(),
+                                ans.to_string(),
+                            ))?;
+                            return Ok(ans);
+                        }
+                        Err(msg) => {
+                            term.clear_line()?;
+                            if active_err_msg {
+                                term.clear_last_lines(1)?;
+                            }
+                            term.write_line(&format!("{} {}", style('X').red(), style(msg).red()))?;
+                            active_err_msg = true;
+                        }
+                    }
+                }
+                _ => continue,
+            }
+            typed.clear();
+        }
+    }
+
+    /// A tenth of the range, dividing before subtracting so wide ranges don't overflow
+    /// The initial value kept within the bounds, erroring when the bounds are inverted
+    fn initial_value(&self) -> Result<T> {
+        if self.min > self.max {
+            return Err(Other("The minimum must not be greater than the maximum"));
+        }
+        Ok(self.initial.unwrap_or(self.min).clamp(self.min, self.max))
+    }
+
+    fn default_page_step(&self) -> T {
+        let ten = T::from(10).unwrap_or(T::one());
+        (self.max / ten)
+            .saturating_sub(self.min / ten)
+            .max(self.step)
+    }
+
+    fn increase(&self, value: T, step: T) -> T {
+        value.saturating_add(step).min(self.max)
+    }
+
+    fn decrease(&self, value: T, step: T) -> T {
+        value.saturating_sub(step).max(self.min)
+    }
+
+    fn formatted_slider(&self, value: T, typed: &str) -> String {
+        let range = (self.max.to_f64().unwrap_or_default() - self.min.to_f64().unwrap_or_default())
+            .max(1.0);
+        let offset = value.to_f64().unwrap_or_default() - self.min.to_f64().unwrap_or_default();
+        let filled = ((offset / range) * (WIDTH - 1) as f64).round() as usize;
+
+        let bar = format!(
+            "{}{}{}",
+            style("━".repeat(filled)).red(),
+            style('●').red().bold(),
+            style("─".repeat(WIDTH - 1 - filled)).dim()
+        );
+        let shown = if typed.is_empty() {
+            style(value.to_string()).bold().to_string()
+        } else {
+            format!("{} {typed}", style(ARROW.to_string().repeat(2)).red())
+        };
+        format!("{} {bar} {}  {shown}", self.min, self.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_page_step_with_default_bounds() {
+        assert_eq!(Slider::<i32>::new("").default_page_step(), 429_496_728);
+        assert_eq!(
+            Slider::<i64>::new("").default_page_step(),
+            1_844_674_407_370_955_160
+        );
+        assert_eq!(Slider::<u8>::new("").default_page_step(), 25);
+    }
+
+    #[test]
+    fn default_page_step_is_at_least_the_step() {
+        let slider = Slider::<u8>::new("").max(20).step(5);
+        assert_eq!(slider.default_page_step(), 5);
+        let slider = Slider::<i32>::new("").min(-50).max(50);
+        assert_eq!(slider.default_page_step(), 10);
+    }
+
+    #[test]
+    fn initial_value_is_clamped() {
+        let slider = Slider::<i32>::new("").min(0).max(10).initial(20);
+        assert_eq!(slider.initial_value().unwrap(), 10);
+        let slider = Slider::<i32>::new("").min(5).max(10);
+        assert_eq!(slider.initial_value().unwrap(), 5);
+    }
+
+    #[test]
+    fn min_above_max_is_an_error() {
+        let slider = Slider::<i32>::new("").min(10).max(5);
+        assert!(matches!(
+            slider.initial_value(),
+            Err(Other("The minimum must not be greater than the maximum"))
+        ));
+    }
+}
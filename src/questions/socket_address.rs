@@ -0,0 +1,122 @@
+#![cfg(feature = "net")]
+
+use crate::questions::ip::ip_error;
+use crate::questions::port::parse_port;
+use crate::{Input, Result};
+use console::Term;
+use std::net::SocketAddr;
+use std::result;
+use std::str::FromStr;
+
+/// Get a socket address such as `127.0.0.1:8080` or `[::1]:8080` from the user
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::SocketAddress;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let addr = SocketAddress::new("Which address should the server bind to?").ask(&term)?;
+/// println!("Binding to {addr}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct SocketAddress {
+    title: String,
+}
+
+impl SocketAddress {
+    /// Creates a socket address input with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+        }
+    }
+
+    /// Ask the question getting the inputted socket address as a result
+    pub fn ask(&self, term: &Term) -> Result<SocketAddr> {
+        Input::new(self.title.clone())
+            .validator(Self::validator)
+            .ask(term)
+    }
+
+    fn validator(input: String) -> result::Result<SocketAddr, String> {
+        let input = input.trim();
+        if let Ok(addr) = SocketAddr::from_str(input) {
+            return Ok(addr);
+        }
+        if input.is_empty() {
+            return Err("You must enter an address".to_string());
+        }
+
+        let (ip, port) = match input.strip_prefix('[') {
+            Some(rest) => {
+                let Some((ip, rest)) = rest.split_once(']') else {
+                    return Err("Missing a closing ']'".to_string());
+                };
+                let Some(port) = rest.strip_prefix(':') else {
+                    return Err("Missing a port such as :8080".to_string());
+                };
+                (ip, port)
+            }
+            None => {
+                let Some((ip, port)) = input.rsplit_once(':') else {
+                    return Err("Missing a port such as :8080".to_string());
+                };
+                if ip.contains(':') {
+                    return Err("IPv6 addresses must be in brackets such as [::1]:8080".to_string());
+                }
+                (ip, port)
+            }
+        };
+        parse_port(port)?;
+        Err(ip_error(ip, true, true).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_socket_addresses() {
+        let cases = [
+            "127.0.0.1:8080",
+            " 0.0.0.0:0 ",
+            "[::1]:443",
+            "[fe80::1]:65535",
+        ];
+        for input in cases {
+            assert_eq!(
+                SocketAddress::validator(input.to_string()),
+                Ok(SocketAddr::from_str(input.trim()).unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn explains_invalid_socket_addresses() {
+        let cases = [
+            ("", "You must enter an address"),
+            ("127.0.0.1", "Missing a port such as :8080"),
+            ("[::1]", "Missing a port such as :8080"),
+            ("[::1:8080", "Missing a closing ']'"),
+            (
+                "::1:8080",
+                "IPv6 addresses must be in brackets such as [::1]:8080",
+            ),
+            ("127.0.0.1:65536", "The port must be between 0 and 65535"),
+            ("127.0.0.1:", "You must enter a port"),
+            ("127.0.0.01:80", "Parts can't have leading zeros"),
+            ("[1::2::3]:80", "'::' can only appear once"),
+        ];
+        for (input, err) in cases {
+            assert_eq!(
+                SocketAddress::validator(input.to_string()),
+                Err(err.to_string()),
+                "{input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::questions::OptionList;
+use crate::Error::Other;
+use crate::Result;
+use console::{measure_text_width, style, truncate_str, Term};
+
+type CellFunc<T> = dyn Fn(&T) -> String;
+
+/// Get a single row from a table of items only known at runtime from the user
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::TableSelect;
+///
+/// struct Release {
+///     version: &'static str,
+///     date: &'static str,
+///     downloads: u32,
+/// }
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let releases = vec![
+///     Release { version: "0.2.0", date: "2024-03-01", downloads: 1200 },
+///     Release { version: "0.1.0", date: "2023-11-20", downloads: 4300 },
+/// ];
+/// let release = TableSelect::new("Which release should be installed?", releases)
+///     .column("Version", |r| r.version.to_string())
+///     .column("Released", |r| r.date.to_string())
+///     .column("Downloads", |r| r.downloads.to_string())
+///     .sort_by_key(|r| r.version)
+///     .ask(&term)?;
+/// println!("Installing {}", release.version);
+/// # Ok(())
+/// # }
+/// ```
+pub struct TableSelect<T> {
+    title: String,
+    items: Vec<T>,
+    columns: Vec<(String, Box<CellFunc<T>>)>,
+    initial: Option<usize>,
+}
+
+impl<T> TableSelect<T> {
+    /// Creates a table select with the given title and items
+    pub fn new(title: impl Into<String>, items: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            title: title.into(),
+            items: items.into_iter().collect(),
+            columns: Vec::new(),
+            initial: None,
+        }
+    }
+
+    /// Add a column with the given header and function for displaying its cell of an item,
+    /// the first column is used for the answer once selected
+    pub fn column(
+        mut self,
+        header: impl Into<String>,
+        cell: impl Fn(&T) -> String + 'static,
+    ) -> Self {
+        self.columns.push((header.into(), Box::new(cell)));
+        self
+    }
+
+    /// Sort the rows by the key returned for each item, indices then refer to the sorted rows
+    pub fn sort_by_key<K: Ord>(mut self, key: impl FnMut(&T) -> K) -> Self {
+        self.items.sort_by_key(key);
+        self
+    }
+
+    /// Set the index of the initially selected row
+    pub fn initial(mut self, initial: usize) -> Self {
+        self.initial = Some(initial);
+        self
+    }
+
+    /// Ask the question getting the selected item as a result
+    pub fn ask(mut self, term: &Term) -> Result<T> {
+        let index = self.ask_index(term)?;
+        Ok(self.items.swap_remove(index))
+    }
+
+    /// Ask the question getting the index of the selected row as a result
+    pub fn ask_index(&self, term: &Term) -> Result<usize> {
+        self.run(term, false)?.ok_or(Other("No row was selected"))
+    }
+
+    /// Ask the question optionally getting either the selected item or none as a result
+    pub fn ask_opt(mut self, term: &Term) -> Result<Option<T>> {
+        let index = self.ask_index_opt(term)?;
+        Ok(index.map(|n| self.items.swap_remove(n)))
+    }
+
+    /// Ask the question optionally getting either the index of the selected row or none as a result
+    pub fn ask_index_opt(&self, term: &Term) -> Result<Option<usize>> {
+        self.run(term, true)
+    }
+
+    fn run(&self, term: &Term, optional: bool) -> Result<Option<usize>> {
+        if self.items.is_empty() {
+            return Err(Other("There are no items to select from"));
+        }
+        if self.columns.is_empty() {
+            return Err(Other("The table has no columns"));
+        }
+        let cells: Vec<Vec<String>> = self
+            .items
+            .iter()
+            .map(|item| self.columns.iter().map(|(_, cell)| cell(item)).collect())
+            .collect();
+        let widths = self.widths(term, &cells);
+        let row = |values: &mut dyn Iterator<Item = &str>| {
+            values
+                .zip(&widths)
+                .map(|(value, width)| {
+                    let value = if measure_text_width(value) > *width {
+                        truncate_str(value, *width, "…")
+                    } else {
+                        value.into()
+                    };
+                    let padding = " ".repeat(width - measure_text_width(&value));
+                    format!("{value}{padding}")
+                })
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        };
+
+        let header = row(&mut self.columns.iter().map(|(header, _)| header.as_str()));
+        let mut list = OptionList::new(
+            cells
+                .iter()
+                .map(|values| row(&mut values.iter().map(String::as_str)))
+                .collect(),
+            vec![None; cells.len()],
+            vec![None; cells.len()],
+            false,
+        )
+        .with_header(format!("  {}", style(header).bold().underlined()))
+        .with_answers(
+            cells
+                .into_iter()
+                .map(|mut values| values.swap_remove(0))
+                .collect(),
+        );
+        if let Some(initial) = self.initial {
+            list.set_cursor(initial);
+        }
+        list.select(term, &self.title, optional)
+    }
+
+    /// The width of each column, shrinking the widest columns until the table fits the terminal
+    fn widths(&self, term: &Term, cells: &[Vec<String>]) -> Vec<usize> {
+        let mut widths: Vec<usize> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(n, (header, _))| {
+                cells
+                    .iter()
+                    .map(|row| measure_text_width(&row[n]))
+                    .chain([measure_text_width(header)])
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        let (_, columns) = term.size();
+        let available = (columns as usize).saturating_sub(2 + 2 * (widths.len() - 1));
+        while widths.iter().sum::<usize>() > available {
+            let Some(widest) = widths.iter_mut().filter(|w| **w > 1).max() else {
+                break;
+            };
+            *widest -= 1;
+        }
+        widths
+    }
+}
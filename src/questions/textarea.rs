@@ -0,0 +1,363 @@
+use crate::questions::{formatted_answered_question, formatted_question, multiline_summary, ARROW};
+use crate::{NoValidator, Result, Validator};
+use console::{style, Key, Term};
+use std::marker::PhantomData;
+use std::result;
+
+/// Get multiple lines of text from the user
+///
+/// Pressing alt+enter starts a new line and enter submits the text. A
+/// plain newline, as sent by ctrl+j, can't be told apart from enter so
+/// submits as well
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::TextArea;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let address = TextArea::new("What's your address?")
+///     .max_lines(4)
+///     .validator(|text| Ok(text))
+///     .ask(&term)?;
+/// println!("Sending it to\n{address}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct TextArea<T, Validator = NoValidator> {
+    title: String,
+    default: Option<String>,
+    max_lines: usize,
+    validator: Validator,
+    data: PhantomData<T>,
+}
+
+impl<T> TextArea<T, NoValidator> {
+    /// Creates a text area with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            default: None,
+            max_lines: usize::MAX,
+            validator: NoValidator,
+            data: PhantomData::<T>,
+        }
+    }
+
+    /// Set the function for validating and returning the parsed type
+    pub fn validator(
+        self,
+        value: impl Fn(String) -> result::Result<T, String> + 'static,
+    ) -> TextArea<T, Validator<T>> {
+        let validator = Validator::<T> {
+            method: Box::new(value),
+        };
+        TextArea::<T, Validator<T>> {
+            title: self.title,
+            default: self.default,
+            max_lines: self.max_lines,
+            validator,
+            data: Default::default(),
+        }
+    }
+}
+
+impl<T> TextArea<T, Validator<T>> {
+    /// Ask the question getting the validated type as a result
+    pub fn ask(&self, term: &Term) -> Result<T> {
+        term.write_line(&formatted_question(
+            self.title.clone(),
+            &[("alt+enter", "new line"), ("enter", "proceed")],
+        ))?;
+
+        let mut lines = self.default_lines();
+        let mut row = lines.len() - 1;
+        let mut col = lines[row].len();
+        let mut active_err_msg = false;
+
+        loop {
+            Self::write_lines(term, &lines, row, col)?;
+            let (rendered_lines, rendered_row) = (lines.len(), row);
+
+            loop {
+                let key = term.read_key()?;
+                let rerender = match key {
+                    Key::Enter => {
+                        let text = lines
+                            .iter()
+                            .map(|line| line.iter().collect::<String>())
+                            .collect::<Vec<_>>()
+                            .join("\n");
+                        Self::clear_lines(term, rendered_lines, rendered_row)?;
+                        match (self.validator.method)(text.clone()) {
+                            Ok(ans) => {
+                                term.clear_last_lines(if !active_err_msg { 1 } else { 2 })?;
+                                term.write_line(&formatted_answered_question(
+                                    self.title.clone(),
+                                    multiline_summary(&text),
+                                ))?;
+                                return Ok(ans);
+                            }
+                            Err(msg) => {
+                                if active_err_msg {
+                                    term.clear_last_lines(1)?;
+                                }
+                                term.write_line(&format!(
+                                    "{} {}",
+                                    style('X').red(),
+                                    style(msg).red()
+                                ))?;
+                                active_err_msg = true;
+                                break;
+                            }
+                        }
+                    }
+                    key => handle_key(&mut lines, &mut row, &mut col, &key, self.max_lines),
+                };
+
+                if rerender {
+                    Self::clear_lines(term, rendered_lines, rendered_row)?;
+                    break;
+                }
+            }
+        }
+    }
+
+    fn default_lines(&self) -> Vec<Vec<char>> {
+        let mut lines: Vec<Vec<char>> = match &self.default {
+            Some(default) => default
+                .lines()
+                .take(self.max_lines)
+                .map(|l| l.chars().collect())
+                .collect(),
+            None => Vec::new(),
+        };
+        if lines.is_empty() {
+            lines.push(Vec::new());
+        }
+        lines
+    }
+
+    fn write_lines(term: &Term, lines: &[Vec<char>], row: usize, col: usize) -> Result<()> {
+        for (n, line) in lines.iter().enumerate() {
+            let prefix = if n == 0 {
+                style(ARROW.to_string().repeat(2)).red().to_string()
+            } else {
+                String::from("  ")
+            };
+            term.write_line(&format!("{prefix} {}", line.iter().collect::<String>()))?;
+        }
+        term.move_cursor_up(lines.len() - row)?;
+        term.move_cursor_right(col + 3)?;
+        Ok(())
+    }
+
+    fn clear_lines(term: &Term, lines: usize, row: usize) -> Result<()> {
+        term.move_cursor_down(lines - row)?;
+        term.clear_line()?;
+        term.clear_last_lines(lines)?;
+        Ok(())
+    }
+}
+
+impl<T, Validator> TextArea<T, Validator> {
+    /// Set the default value of the text
+    pub fn default(mut self, value: impl Into<String>) -> Self {
+        self.default = Some(value.into());
+        self
+    }
+
+    /// Set the maximum number of lines allowed, a longer default is cut short
+    pub fn max_lines(mut self, value: usize) -> Self {
+        self.max_lines = value.max(1);
+        self
+    }
+}
+
+/// Applies an editing key to the lines at the cursor, returning whether anything changed
+fn handle_key(
+    lines: &mut Vec<Vec<char>>,
+    row: &mut usize,
+    col: &mut usize,
+    key: &Key,
+    max_lines: usize,
+) -> bool {
+    match key {
+        Key::Char(c) if !c.is_control() => {
+            lines[*row].insert(*col, *c);
+            *col += 1;
+        }
+        Key::UnknownEscSeq(seq)
+            if matches!(seq.as_slice(), ['\r'] | ['\n']) && lines.len() < max_lines =>
+        {
+            let rest = lines[*row].split_off(*col);
+            lines.insert(*row + 1, rest);
+            *row += 1;
+            *col = 0;
+        }
+        Key::Backspace if *col > 0 => {
+            *col -= 1;
+            lines[*row].remove(*col);
+        }
+        Key::Backspace if *row > 0 => {
+            let line = lines.remove(*row);
+            *row -= 1;
+            *col = lines[*row].len();
+            lines[*row].extend(line);
+        }
+        Key::Del if *col < lines[*row].len() => {
+            lines[*row].remove(*col);
+        }
+        Key::Del if *row < lines.len() - 1 => {
+            let line = lines.remove(*row + 1);
+            lines[*row].extend(line);
+        }
+        Key::ArrowLeft if *col > 0 => *col -= 1,
+        Key::ArrowLeft if *row > 0 => {
+            *row -= 1;
+            *col = lines[*row].len();
+        }
+        Key::ArrowRight if *col < lines[*row].len() => *col += 1,
+        Key::ArrowRight if *row < lines.len() - 1 => {
+            *row += 1;
+            *col = 0;
+        }
+        Key::ArrowUp if *row > 0 => {
+            *row -= 1;
+            *col = (*col).min(lines[*row].len());
+        }
+        Key::ArrowDown if *row < lines.len() - 1 => {
+            *row += 1;
+            *col = (*col).min(lines[*row].len());
+        }
+        Key::Home if *col > 0 => *col = 0,
+        Key::End if *col < lines[*row].len() => *col = lines[*row].len(),
+        _ => return false,
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_cut_to_max_lines() {
+        let text_area = TextArea::new("Address")
+            .default("1 Main St\nSpringfield\nUSA")
+            .max_lines(2)
+            .validator(Ok);
+        let lines = text_area.default_lines();
+        assert_eq!(
+            lines,
+            [
+                "1 Main St".chars().collect::<Vec<_>>(),
+                "Springfield".chars().collect(),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_default_has_a_line() {
+        let text_area = TextArea::new("Address").validator(Ok);
+        assert_eq!(text_area.default_lines(), [Vec::<char>::new()]);
+    }
+
+    fn lines(text: &str) -> Vec<Vec<char>> {
+        text.split('\n')
+            .map(|line| line.chars().collect())
+            .collect()
+    }
+
+    #[test]
+    fn edits_across_lines() {
+        let mut text = lines("ab\ncd");
+        let (mut row, mut col) = (0, 1);
+        let newline = Key::UnknownEscSeq(vec!['\r']);
+        assert!(handle_key(&mut text, &mut row, &mut col, &newline, 5));
+        assert_eq!((text.clone(), row, col), (lines("a\nb\ncd"), 1, 0));
+        assert!(handle_key(
+            &mut text,
+            &mut row,
+            &mut col,
+            &Key::Backspace,
+            5
+        ));
+        assert_eq!((text.clone(), row, col), (lines("ab\ncd"), 0, 1));
+        assert!(handle_key(&mut text, &mut row, &mut col, &Key::End, 5));
+        assert!(handle_key(&mut text, &mut row, &mut col, &Key::Del, 5));
+        assert_eq!((text.clone(), row, col), (lines("abcd"), 0, 2));
+        assert!(handle_key(
+            &mut text,
+            &mut row,
+            &mut col,
+            &Key::Char('!'),
+            5
+        ));
+        assert_eq!((text, row, col), (lines("ab!cd"), 0, 3));
+    }
+
+    #[test]
+    fn moves_between_lines() {
+        let mut text = lines("abc\nd");
+        let (mut row, mut col) = (0, 3);
+        assert!(handle_key(
+            &mut text,
+            &mut row,
+            &mut col,
+            &Key::ArrowDown,
+            5
+        ));
+        assert_eq!((row, col), (1, 1));
+        assert!(handle_key(
+            &mut text,
+            &mut row,
+            &mut col,
+            &Key::ArrowLeft,
+            5
+        ));
+        assert!(handle_key(
+            &mut text,
+            &mut row,
+            &mut col,
+            &Key::ArrowLeft,
+            5
+        ));
+        assert_eq!((row, col), (0, 3));
+        assert!(handle_key(
+            &mut text,
+            &mut row,
+            &mut col,
+            &Key::ArrowRight,
+            5
+        ));
+        assert_eq!((row, col), (1, 0));
+    }
+
+    #[test]
+    fn ignores_keys_that_change_nothing() {
+        let mut text = lines("ab\ncd");
+        let newline = Key::UnknownEscSeq(vec!['\r']);
+        let ignored = [
+            (0, 0, Key::ArrowUp),
+            (0, 0, Key::ArrowLeft),
+            (0, 0, Key::Backspace),
+            (0, 0, Key::Home),
+            (1, 2, Key::ArrowDown),
+            (1, 2, Key::ArrowRight),
+            (1, 2, Key::Del),
+            (1, 2, Key::End),
+            (1, 1, Key::Tab),
+            (1, 1, Key::Escape),
+            (1, 1, newline),
+        ];
+        for (mut row, mut col, key) in ignored {
+            assert!(
+                !handle_key(&mut text, &mut row, &mut col, &key, 2),
+                "{key:?}"
+            );
+        }
+        assert_eq!(text, lines("ab\ncd"));
+    }
+}
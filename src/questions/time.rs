@@ -0,0 +1,266 @@
+use crate::{Input, Result};
+#[cfg(feature = "date")]
+use chrono::{NaiveTime, Timelike};
+use console::Term;
+use std::fmt;
+use std::result;
+
+/// A time of day with second precision, as answered by [`Time`]
+///
+/// With the `date` feature it converts to and from [`chrono::NaiveTime`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeOfDay {
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+impl TimeOfDay {
+    /// Midnight, the earliest time of day
+    pub const MIN: Self = Self {
+        hour: 0,
+        minute: 0,
+        second: 0,
+    };
+    /// One second before midnight, the latest time of day
+    pub const MAX: Self = Self {
+        hour: 23,
+        minute: 59,
+        second: 59,
+    };
+
+    /// Creates a time of day, or `None` if any part is out of range
+    pub fn new(hour: u32, minute: u32, second: u32) -> Option<Self> {
+        (hour < 24 && minute < 60 && second < 60).then_some(Self {
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    /// The hour from 0 to 23
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// The minute from 0 to 59
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    /// The second from 0 to 59
+    pub fn second(&self) -> u32 {
+        self.second
+    }
+}
+
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)?;
+        if self.second != 0 {
+            write!(f, ":{:02}", self.second)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "date")]
+impl From<NaiveTime> for TimeOfDay {
+    fn from(time: NaiveTime) -> Self {
+        Self {
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+        }
+    }
+}
+
+#[cfg(feature = "date")]
+impl From<TimeOfDay> for NaiveTime {
+    fn from(time: TimeOfDay) -> Self {
+        NaiveTime::from_hms_opt(time.hour, time.minute, time.second).unwrap()
+    }
+}
+
+/// Get a time of day input from the user such as `14:30` or `2:30pm`
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::{Time, TimeOfDay};
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let time = Time::new("When does the meeting start?")
+///     .min(TimeOfDay::new(9, 0, 0).unwrap())
+///     .max(TimeOfDay::new(17, 0, 0).unwrap())
+///     .ask(&term)?;
+/// println!("The meeting starts at {time}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct Time {
+    title: String,
+    min: TimeOfDay,
+    max: TimeOfDay,
+}
+
+impl Time {
+    /// Creates a time input with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            min: TimeOfDay::MIN,
+            max: TimeOfDay::MAX,
+        }
+    }
+
+    /// Sets the earliest time accepted by the input
+    pub fn min(mut self, min: impl Into<TimeOfDay>) -> Self {
+        self.min = min.into();
+        self
+    }
+
+    /// Sets the latest time accepted by the input
+    pub fn max(mut self, max: impl Into<TimeOfDay>) -> Self {
+        self.max = max.into();
+        self
+    }
+
+    /// Ask the question getting the inputted time as a result
+    pub fn ask(&self, term: &Term) -> Result<TimeOfDay> {
+        let min = self.min;
+        let max = self.max;
+        Input::new(self.title.clone())
+            .validator(move |input| Self::validator(input, min, max))
+            .display(|ans| ans.to_string())
+            .ask(term)
+    }
+
+    fn validator(
+        input: String,
+        min: TimeOfDay,
+        max: TimeOfDay,
+    ) -> result::Result<TimeOfDay, String> {
+        let ans = parse_time(&input)?;
+        if ans < min {
+            return Err(format!("Too early! Must be at or after {min}"));
+        }
+        if ans > max {
+            return Err(format!("Too late! Must be at or before {max}"));
+        }
+        Ok(ans)
+    }
+}
+
+fn parse_time(input: &str) -> result::Result<TimeOfDay, String> {
+    let input = input.trim().to_lowercase();
+    if input.is_empty() {
+        return Err("You must enter a time".to_string());
+    }
+
+    let (clock, meridiem) = if let Some(clock) = input.strip_suffix("am") {
+        (clock.trim_end(), Some(false))
+    } else if let Some(clock) = input.strip_suffix("pm") {
+        (clock.trim_end(), Some(true))
+    } else {
+        (input.as_str(), None)
+    };
+
+    let mut parts = clock.split(':').map(|part| {
+        if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid time, must be written like 14:30 or 2:30pm".to_string());
+        }
+        part.parse::<u32>()
+            .map_err(|_| "Invalid time, must be written like 14:30 or 2:30pm".to_string())
+    });
+    let hour = parts.next().unwrap_or(Ok(0))?;
+    let minute = parts.next().transpose()?.unwrap_or(0);
+    let second = parts.next().transpose()?.unwrap_or(0);
+    if parts.next().is_some() {
+        return Err("Invalid time, must be written like 14:30 or 2:30pm".to_string());
+    }
+
+    let hour = match meridiem {
+        Some(_) if hour == 0 || hour > 12 => {
+            return Err("The hour must be between 1 and 12 when using am or pm".to_string())
+        }
+        Some(pm) => hour % 12 + if pm { 12 } else { 0 },
+        None => hour,
+    };
+    if hour > 23 {
+        return Err("The hour must be between 0 and 23".to_string());
+    }
+    if minute > 59 {
+        return Err("The minutes must be between 0 and 59".to_string());
+    }
+    if second > 59 {
+        return Err("The seconds must be between 0 and 59".to_string());
+    }
+
+    TimeOfDay::new(hour, minute, second).ok_or("Invalid time".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_times() {
+        let cases = [
+            ("14:30", (14, 30, 0)),
+            ("9", (9, 0, 0)),
+            ("09:05:07", (9, 5, 7)),
+            ("2:30pm", (14, 30, 0)),
+            ("2:30 PM", (14, 30, 0)),
+            ("12am", (0, 0, 0)),
+            ("12pm", (12, 0, 0)),
+            (" 23:59:59 ", (23, 59, 59)),
+        ];
+        for (input, (hour, minute, second)) in cases {
+            assert_eq!(
+                parse_time(input),
+                Ok(TimeOfDay::new(hour, minute, second).unwrap()),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_times() {
+        let invalid = "Invalid time, must be written like 14:30 or 2:30pm";
+        let cases = [
+            ("", "You must enter a time"),
+            ("14:", invalid),
+            ("1:2:3:4", invalid),
+            ("noon", invalid),
+            (
+                "0am",
+                "The hour must be between 1 and 12 when using am or pm",
+            ),
+            (
+                "13pm",
+                "The hour must be between 1 and 12 when using am or pm",
+            ),
+            ("24:00", "The hour must be between 0 and 23"),
+            ("12:60", "The minutes must be between 0 and 59"),
+            ("12:00:60", "The seconds must be between 0 and 59"),
+        ];
+        for (input, err) in cases {
+            assert_eq!(parse_time(input), Err(err.to_string()), "{input}");
+        }
+    }
+
+    #[test]
+    fn formats_times() {
+        let cases = [
+            ((0, 0, 0), "00:00"),
+            ((9, 5, 0), "09:05"),
+            ((23, 59, 59), "23:59:59"),
+        ];
+        for ((hour, minute, second), formatted) in cases {
+            let time = TimeOfDay::new(hour, minute, second).unwrap();
+            assert_eq!(time.to_string(), formatted);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::questions::tree_select::Tree;
+use crate::questions::{
+    formatted_answered_question, formatted_min_max_error, formatted_question, FILLED_DOT,
+    OUTLINE_DOT,
+};
+use crate::{Result, TreeNode};
+use console::{style, Key, Term};
+
+const PARTIAL_DOT: char = '◐';
+
+/// Get multiple nodes from a tree of options from the user
+///
+/// Selecting a parent selects all of its children, a parent shows whether all, none
+/// or only some of its children are selected. Pressing right expands a node and left
+/// collapses it or moves to its parent
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::{TreeMultiSelect, TreeNode};
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let tree = vec![
+///     TreeNode::new("quizzard", "quizzard")
+///         .child(TreeNode::new("lib", "quizzard/lib"))
+///         .child(TreeNode::new("examples", "quizzard/examples")),
+///     TreeNode::new("quizzard_derive", "quizzard_derive")
+///         .child(TreeNode::new("lib", "quizzard_derive/lib")),
+/// ];
+/// let targets = TreeMultiSelect::new("Which targets should be built?", tree)
+///     .min(1)
+///     .ask(&term)?;
+/// println!("Building {targets:?}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct TreeMultiSelect<T> {
+    title: String,
+    roots: Vec<TreeNode<T>>,
+    expanded: bool,
+    min: usize,
+    max: usize,
+}
+
+impl<T> TreeMultiSelect<T> {
+    /// Creates a tree select with the given title and top level nodes
+    pub fn new(title: impl Into<String>, roots: impl IntoIterator<Item = TreeNode<T>>) -> Self {
+        Self {
+            title: title.into(),
+            roots: roots.into_iter().collect(),
+            expanded: false,
+            min: 0,
+            max: usize::MAX,
+        }
+    }
+
+    /// Set whether every node starts expanded
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Set the minimum nodes without children allowed to be selected
+    pub fn min(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the maximum nodes without children allowed to be selected
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Ask the question getting the values of the selected nodes without children as a result
+    pub fn ask(self, term: &Term) -> Result<Vec<T>> {
+        let mut tree = Tree::new(self.roots, self.expanded)?;
+        let mut selected = vec![false; tree.nodes.len()];
+        let mut cursor = 0;
+        let mut active_err_msg = false;
+        term.write_line(&formatted_question(
+            self.title.clone(),
+            &[("space", "select"), ("enter", "proceed")],
+        ))?;
+
+        loop {
+            Self::write_options(term, &tree, cursor, &selected)?;
+            let rendered = tree.rendered_len(term, cursor);
+
+            loop {
+                let key = term.read_key()?;
+                let rerender = match key {
+                    Key::Char(' ') => {
+                        let all = tree.leaves(cursor).all(|n| selected[n]);
+                        for n in tree.leaves(cursor).collect::<Vec<_>>() {
+                            selected[n] = !all;
+                        }
+                        true
+                    }
+                    Key::Enter => {
+                        let count = selected.iter().filter(|s| **s).count();
+                        if count < self.min || count > self.max {
+                            term.clear_last_lines(rendered)?;
+                            if active_err_msg {
+                                term.clear_last_lines(1)?;
+                            }
+                            term.write_line(&formatted_min_max_error(count, self.min, self.max))?;
+                            active_err_msg = true;
+                            break;
+                        }
+                        term.clear_last_lines(rendered + if !active_err_msg { 1 } else { 2 })?;
+                        let chosen: Vec<usize> =
+                            (0..tree.nodes.len()).filter(|n| selected[*n]).collect();
+                        term.write_line(&formatted_answered_question(
+                            self.title.clone(),
+                            if !chosen.is_empty() {
+                                chosen
+                                    .iter()
+                                    .map(|n| tree.nodes[*n].label.as_str())
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            } else {
+                                "Skipped".to_string()
+                            },
+                        ))?;
+                        return Ok(chosen
+                            .into_iter()
+                            .filter_map(|n| tree.nodes[n].value.take())
+                            .collect());
+                    }
+                    key => tree.move_cursor(key, &mut cursor),
+                };
+
+                if rerender {
+                    term.clear_last_lines(rendered)?;
+                    break;
+                }
+            }
+        }
+    }
+
+    fn write_options(term: &Term, tree: &Tree<T>, cursor: usize, selected: &[bool]) -> Result<()> {
+        for n in tree.page(term, cursor) {
+            let branch = tree.formatted_branch(n);
+            let label = &tree.nodes[n].label;
+            let leaves = tree.leaves(n).count();
+            let dot = match tree.leaves(n).filter(|n| selected[*n]).count() {
+                0 => OUTLINE_DOT,
+                count if count == leaves => FILLED_DOT,
+                _ => PARTIAL_DOT,
+            };
+            let arm = if n == cursor {
+                format!(
+                    "{branch} {} {}",
+                    style(dot).red(),
+                    style(label).red().bold()
+                )
+            } else {
+                format!("{branch} {dot} {label}")
+            };
+            term.write_line(&arm)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,381 @@
+use crate::questions::{
+    formatted_answered_question, formatted_question, page, page_len, per_page, ARROW,
+};
+use crate::Error::Other;
+use crate::Result;
+use console::{style, Key, Term};
+
+const COLLAPSED: char = '▸';
+const EXPANDED: char = '▾';
+
+/// A node in the tree of a [`TreeSelect`] or [`TreeMultiSelect`](crate::TreeMultiSelect)
+pub struct TreeNode<T> {
+    label: String,
+    value: T,
+    children: Vec<TreeNode<T>>,
+}
+
+impl<T> TreeNode<T> {
+    /// Creates a node with the given label and value
+    pub fn new(label: impl Into<String>, value: T) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            children: Vec::new(),
+        }
+    }
+
+    /// Add a child to the node
+    pub fn child(mut self, child: TreeNode<T>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Add multiple children to the node
+    pub fn children(mut self, children: impl IntoIterator<Item = TreeNode<T>>) -> Self {
+        self.children.extend(children);
+        self
+    }
+}
+
+/// A tree flattened in pre-order, so the descendants of a node are the nodes
+/// after it up until its end
+pub(crate) struct Tree<T> {
+    pub(crate) nodes: Vec<FlatNode<T>>,
+    pub(crate) expanded: Vec<bool>,
+}
+
+pub(crate) struct FlatNode<T> {
+    pub(crate) label: String,
+    pub(crate) value: Option<T>,
+    pub(crate) depth: usize,
+    pub(crate) parent: Option<usize>,
+    pub(crate) end: usize,
+}
+
+impl<T> Tree<T> {
+    pub(crate) fn new(roots: Vec<TreeNode<T>>, expanded: bool) -> Result<Self> {
+        if roots.is_empty() {
+            return Err(Other("There are no items to select from"));
+        }
+        let mut nodes = Vec::new();
+        for root in roots {
+            Self::flatten(root, 0, None, &mut nodes);
+        }
+        let expanded = vec![expanded; nodes.len()];
+        Ok(Self { nodes, expanded })
+    }
+
+    fn flatten(
+        node: TreeNode<T>,
+        depth: usize,
+        parent: Option<usize>,
+        nodes: &mut Vec<FlatNode<T>>,
+    ) {
+        let index = nodes.len();
+        nodes.push(FlatNode {
+            label: node.label,
+            value: Some(node.value),
+            depth,
+            parent,
+            end: index + 1,
+        });
+        for child in node.children {
+            Self::flatten(child, depth + 1, Some(index), nodes);
+        }
+        nodes[index].end = nodes.len();
+    }
+
+    pub(crate) fn is_leaf(&self, index: usize) -> bool {
+        self.nodes[index].end == index + 1
+    }
+
+    /// The leaves of the node, which is just the node itself when it has no children
+    pub(crate) fn leaves(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        (index..self.nodes[index].end).filter(|n| self.is_leaf(*n))
+    }
+
+    /// The nodes not hidden inside a collapsed parent
+    pub(crate) fn visible(&self) -> Vec<usize> {
+        let mut visible = Vec::new();
+        let mut n = 0;
+        while n < self.nodes.len() {
+            visible.push(n);
+            n = if self.expanded[n] {
+                n + 1
+            } else {
+                self.nodes[n].end
+            };
+        }
+        visible
+    }
+
+    /// Moves the cursor for the arrow keys, returning whether anything changed
+    pub(crate) fn move_cursor(&mut self, key: Key, cursor: &mut usize) -> bool {
+        let visible = self.visible();
+        let position = visible.iter().position(|n| n == cursor).unwrap_or_default();
+        match key {
+            Key::ArrowUp => {
+                *cursor = visible[position.checked_sub(1).unwrap_or(visible.len() - 1)];
+                true
+            }
+            Key::ArrowDown => {
+                *cursor = visible[(position + 1) % visible.len()];
+                true
+            }
+            Key::ArrowRight if !self.is_leaf(*cursor) => {
+                if self.expanded[*cursor] {
+                    *cursor += 1;
+                } else {
+                    self.expanded[*cursor] = true;
+                }
+                true
+            }
+            Key::ArrowLeft if !self.is_leaf(*cursor) && self.expanded[*cursor] => {
+                self.expanded[*cursor] = false;
+                true
+            }
+            Key::ArrowLeft => match self.nodes[*cursor].parent {
+                Some(parent) => {
+                    *cursor = parent;
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// The indentation and expand marker shown before the label of a node
+    pub(crate) fn formatted_branch(&self, index: usize) -> String {
+        let marker = if self.is_leaf(index) {
+            ' '
+        } else if self.expanded[index] {
+            EXPANDED
+        } else {
+            COLLAPSED
+        };
+        format!("{}{marker}", "  ".repeat(self.nodes[index].depth))
+    }
+
+    /// The number of lines rendered for the visible nodes on the cursor's page
+    pub(crate) fn rendered_len(&self, term: &Term, cursor: usize) -> usize {
+        let visible = self.visible();
+        let position = visible
+            .iter()
+            .position(|n| *n == cursor)
+            .unwrap_or_default();
+        page_len(term, position, visible.len())
+    }
+
+    /// The visible nodes on the cursor's page
+    pub(crate) fn page(&self, term: &Term, cursor: usize) -> Vec<usize> {
+        let per_page = per_page(term);
+        let visible = self.visible();
+        let position = visible
+            .iter()
+            .position(|n| *n == cursor)
+            .unwrap_or_default();
+        let start = page(term, position) * per_page;
+        visible.into_iter().skip(start).take(per_page).collect()
+    }
+}
+
+/// Get a single node from a tree of options from the user
+///
+/// Pressing right expands a node and left collapses it or moves to its parent
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::{TreeNode, TreeSelect};
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let tree = vec![
+///     TreeNode::new("quizzard", "quizzard")
+///         .child(TreeNode::new("lib", "quizzard/lib"))
+///         .child(TreeNode::new("examples", "quizzard/examples")),
+///     TreeNode::new("quizzard_derive", "quizzard_derive")
+///         .child(TreeNode::new("lib", "quizzard_derive/lib")),
+/// ];
+/// let target = TreeSelect::new("Which target should be built?", tree).ask(&term)?;
+/// println!("Building {target}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct TreeSelect<T> {
+    title: String,
+    roots: Vec<TreeNode<T>>,
+    expanded: bool,
+}
+
+impl<T> TreeSelect<T> {
+    /// Creates a tree select with the given title and top level nodes
+    pub fn new(title: impl Into<String>, roots: impl IntoIterator<Item = TreeNode<T>>) -> Self {
+        Self {
+            title: title.into(),
+            roots: roots.into_iter().collect(),
+            expanded: false,
+        }
+    }
+
+    /// Set whether every node starts expanded
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Ask the question getting the value of the selected node as a result
+    pub fn ask(self, term: &Term) -> Result<T> {
+        let mut tree = Tree::new(self.roots, self.expanded)?;
+        let mut cursor = 0;
+        term.write_line(&formatted_question(
+            self.title.clone(),
+            &[("enter", "select")],
+        ))?;
+
+        loop {
+            Self::write_options(term, &tree, cursor)?;
+            let rendered = tree.rendered_len(term, cursor);
+
+            loop {
+                let key = term.read_key()?;
+                let rerender = match key {
+                    Key::Enter => {
+                        term.clear_last_lines(rendered + 1)?;
+                        let node = &mut tree.nodes[cursor];
+                        term.write_line(&formatted_answered_question(
+                            self.title.clone(),
+                            node.label.clone(),
+                        ))?;
+                        return node.value.take().ok_or(Other("Index out of range"));
+                    }
+                    key => tree.move_cursor(key, &mut cursor),
+                };
+
+                if rerender {
+                    term.clear_last_lines(rendered)?;
+                    break;
+                }
+            }
+        }
+    }
+
+    fn write_options(term: &Term, tree: &Tree<T>, cursor: usize) -> Result<()> {
+        for n in tree.page(term, cursor) {
+            let branch = tree.formatted_branch(n);
+            let label = &tree.nodes[n].label;
+            let arm = if n == cursor {
+                format!(
+                    "{} {branch} {}",
+                    style(ARROW).red(),
+                    style(label).red().bold()
+                )
+            } else {
+                format!("  {branch} {label}")
+            };
+            term.write_line(&arm)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two roots, the first with a branch holding two leaves and a leaf of its own
+    fn tree(expanded: bool) -> Tree<&'static str> {
+        let roots = vec![
+            TreeNode::new("a", "a")
+                .child(
+                    TreeNode::new("b", "b")
+                        .children([TreeNode::new("c", "c"), TreeNode::new("d", "d")]),
+                )
+                .child(TreeNode::new("e", "e")),
+            TreeNode::new("f", "f"),
+        ];
+        Tree::new(roots, expanded).unwrap()
+    }
+
+    #[test]
+    fn flattens_depth_first() {
+        let tree = tree(true);
+        let labels: Vec<&str> = tree.nodes.iter().map(|n| n.label.as_str()).collect();
+        assert_eq!(labels, ["a", "b", "c", "d", "e", "f"]);
+        let depths: Vec<usize> = tree.nodes.iter().map(|n| n.depth).collect();
+        assert_eq!(depths, [0, 1, 2, 2, 1, 0]);
+        assert_eq!(tree.nodes[2].parent, Some(1));
+        assert_eq!(tree.nodes[0].end, 5);
+    }
+
+    #[test]
+    fn leaves_of_a_node() {
+        let tree = tree(true);
+        assert_eq!(tree.leaves(0).collect::<Vec<_>>(), [2, 3, 4]);
+        assert_eq!(tree.leaves(1).collect::<Vec<_>>(), [2, 3]);
+        assert_eq!(tree.leaves(5).collect::<Vec<_>>(), [5]);
+    }
+
+    #[test]
+    fn collapsed_nodes_hide_their_children() {
+        assert_eq!(tree(false).visible(), [0, 5]);
+        assert_eq!(tree(true).visible(), [0, 1, 2, 3, 4, 5]);
+        let mut tree = tree(true);
+        tree.expanded[1] = false;
+        assert_eq!(tree.visible(), [0, 1, 4, 5]);
+    }
+
+    #[test]
+    fn arrows_move_through_visible_nodes() {
+        let mut tree = tree(false);
+        let mut cursor = 0;
+        assert!(tree.move_cursor(Key::ArrowDown, &mut cursor));
+        assert_eq!(cursor, 5);
+        assert!(tree.move_cursor(Key::ArrowDown, &mut cursor));
+        assert_eq!(cursor, 0);
+        assert!(tree.move_cursor(Key::ArrowUp, &mut cursor));
+        assert_eq!(cursor, 5);
+    }
+
+    #[test]
+    fn right_expands_then_enters() {
+        let mut tree = tree(false);
+        let mut cursor = 0;
+        assert!(tree.move_cursor(Key::ArrowRight, &mut cursor));
+        assert_eq!((cursor, tree.visible()), (0, vec![0, 1, 4, 5]));
+        assert!(tree.move_cursor(Key::ArrowRight, &mut cursor));
+        assert_eq!(cursor, 1);
+        cursor = 5;
+        assert!(!tree.move_cursor(Key::ArrowRight, &mut cursor));
+    }
+
+    #[test]
+    fn left_collapses_then_goes_to_the_parent() {
+        let mut tree = tree(true);
+        let mut cursor = 2;
+        assert!(tree.move_cursor(Key::ArrowLeft, &mut cursor));
+        assert_eq!(cursor, 1);
+        assert!(tree.move_cursor(Key::ArrowLeft, &mut cursor));
+        assert_eq!((cursor, tree.visible()), (1, vec![0, 1, 4, 5]));
+        assert!(tree.move_cursor(Key::ArrowLeft, &mut cursor));
+        assert_eq!(cursor, 0);
+        cursor = 5;
+        assert!(!tree.move_cursor(Key::ArrowLeft, &mut cursor));
+    }
+
+    #[test]
+    fn branch_markers() {
+        let mut tree = tree(true);
+        tree.expanded[1] = false;
+        assert_eq!(tree.formatted_branch(0), format!("{EXPANDED}"));
+        assert_eq!(tree.formatted_branch(1), format!("  {COLLAPSED}"));
+        assert_eq!(tree.formatted_branch(2), "     ");
+    }
+
+    #[test]
+    fn empty_tree_is_an_error() {
+        assert!(Tree::<()>::new(Vec::new(), false).is_err());
+    }
+}
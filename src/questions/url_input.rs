@@ -0,0 +1,123 @@
+#![cfg(feature = "url")]
+
+use crate::{Input, Result};
+use console::Term;
+use std::result;
+use url::{ParseError, Url};
+
+/// Get a URL input from the user
+///
+/// # Example
+/// ```no_run
+/// use console::Term;
+/// use quizzard::UrlInput;
+///
+/// # fn main() -> Result<(), quizzard::Error> {
+/// let term = Term::stdout();
+/// let url = UrlInput::new("Where is the server hosted?")
+///     .schemes(["http", "https"])
+///     .ask(&term)?;
+/// println!("Connecting to {url}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct UrlInput {
+    title: String,
+    schemes: Vec<String>,
+}
+
+impl UrlInput {
+    /// Creates a URL input with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            schemes: Vec::new(),
+        }
+    }
+
+    /// Set the schemes accepted, such as `"https"`, any scheme is accepted when none are set
+    pub fn schemes(mut self, schemes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.schemes = schemes
+            .into_iter()
+            .map(|scheme| scheme.into().trim_end_matches("://").to_lowercase())
+            .collect();
+        self
+    }
+
+    /// Ask the question getting the inputted URL as a result
+    pub fn ask(&self, term: &Term) -> Result<Url> {
+        let schemes = self.schemes.clone();
+        Input::new(self.title.clone())
+            .validator(move |input| Self::validator(input, &schemes))
+            .ask(term)
+    }
+
+    fn validator(input: String, schemes: &[String]) -> result::Result<Url, String> {
+        let url = Url::parse(input.trim()).map_err(|err| {
+            match err {
+                ParseError::EmptyHost => "The host is empty",
+                ParseError::IdnaError => "The international domain name is invalid",
+                ParseError::InvalidPort => "The port must be between 0 and 65535",
+                ParseError::InvalidIpv4Address => "Invalid IPv4 address",
+                ParseError::InvalidIpv6Address => "Invalid IPv6 address",
+                ParseError::InvalidDomainCharacter => "An invalid character is present in the host",
+                ParseError::RelativeUrlWithoutBase => "Missing a scheme such as https://",
+                ParseError::Overflow => "The URL is too long",
+                _ => "Invalid URL",
+            }
+            .to_string()
+        })?;
+        if !schemes.is_empty() && !schemes.contains(&url.scheme().to_string()) {
+            let schemes: Vec<String> = schemes.iter().map(|s| format!("{s}://")).collect();
+            return Err(format!("Must start with one of: {}", schemes.join(", ")));
+        }
+        Ok(url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_urls() {
+        let url = UrlInput::validator(" https://example.com/docs ".to_string(), &[]).unwrap();
+        assert_eq!(url.as_str(), "https://example.com/docs");
+        let schemes = ["https".to_string()];
+        assert!(UrlInput::validator("https://[::1]:8080".to_string(), &schemes).is_ok());
+    }
+
+    #[test]
+    fn explains_invalid_urls() {
+        let cases = [
+            ("example.com", "Missing a scheme such as https://"),
+            ("https://", "The host is empty"),
+            (
+                "https://example.com:65536",
+                "The port must be between 0 and 65535",
+            ),
+            ("http://256.0.0.1", "Invalid IPv4 address"),
+            ("http://[::g]", "Invalid IPv6 address"),
+            (
+                "ssh://exa^mple.com",
+                "An invalid character is present in the host",
+            ),
+        ];
+        for (input, err) in cases {
+            assert_eq!(
+                UrlInput::validator(input.to_string(), &[]),
+                Err(err.to_string()),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_other_schemes() {
+        let schemes = ["https".to_string(), "ssh".to_string()];
+        assert_eq!(
+            UrlInput::validator("http://example.com".to_string(), &schemes),
+            Err("Must start with one of: https://, ssh://".to_string())
+        );
+    }
+}
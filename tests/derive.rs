@@ -0,0 +1,56 @@
+use quizzard::SelectEnum;
+
+#[test]
+fn derive_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
+
+#[derive(SelectEnum)]
+enum Database {
+    /// A file on disk
+    Sqlite,
+    #[prompt("Postgres", disabled = "Requires the `pg` feature")]
+    Postgres,
+    #[prompt(description = "Any other database", disabled = "Not supported yet")]
+    Custom,
+}
+
+#[test]
+fn derive_descriptions_and_disabled() {
+    assert_eq!(Database::PROMPTS, ["Sqlite", "Postgres", "Custom"]);
+    assert_eq!(
+        Database::DESCRIPTIONS,
+        [Some("A file on disk"), None, Some("Any other database")]
+    );
+    assert_eq!(
+        Database::DISABLED,
+        [
+            None,
+            Some("Requires the `pg` feature"),
+            Some("Not supported yet")
+        ]
+    );
+    assert_eq!(Database::VARIANTS.len(), 3);
+}
+
+// Derived code only names paths through quizzard, so these compile without using console
+#[allow(dead_code)]
+#[derive(SelectEnum)]
+enum Auth {
+    Anonymous,
+    Token(String),
+}
+
+#[allow(dead_code)]
+#[derive(quizzard::Form)]
+struct Settings {
+    auth: Auth,
+    newsletter: Option<bool>,
+}
+
+#[test]
+fn derive_form_is_implemented() {
+    fn is_form<T: quizzard::Form>() {}
+    is_form::<Settings>();
+}
@@ -0,0 +1,9 @@
+use quizzard::Form;
+
+#[derive(Form)]
+struct Profile {
+    #[prompt("How old are you?", min = 18, min = 21)]
+    age: u8,
+}
+
+fn main() {}
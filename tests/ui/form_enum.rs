@@ -0,0 +1,8 @@
+use quizzard::Form;
+
+#[derive(Form)]
+enum Profile {
+    Name(String),
+}
+
+fn main() {}
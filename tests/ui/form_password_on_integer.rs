@@ -0,0 +1,9 @@
+use quizzard::Form;
+
+#[derive(Form)]
+struct Login {
+    #[prompt("What is your pin?", password = true)]
+    pin: u16,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use quizzard::Form;
+
+#[derive(Form)]
+struct Profile(String);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use quizzard::Form;
+
+#[derive(Form)]
+struct Profile {
+    #[prompt("How old are you?", step = 2)]
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use quizzard::Form;
+
+#[derive(Form)]
+struct Profile {
+    #[prompt("Subscribe?", validator = subscribe)]
+    newsletter: bool,
+}
+
+fn subscribe(input: String) -> Result<bool, String> {
+    Ok(input == "yes")
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use quizzard::SelectEnum;
+
+#[derive(SelectEnum)]
+enum Speed {
+    Slow,
+    #[prompt("Fast")]
+    #[prompt("Really fast")]
+    Fast,
+}
+
+fn main() {}
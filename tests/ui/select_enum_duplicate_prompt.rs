@@ -0,0 +1,11 @@
+use quizzard::SelectEnum;
+
+#[derive(SelectEnum)]
+enum Speed {
+    Slow,
+    Fast,
+    #[prompt("Fast")]
+    ReallyFast,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use quizzard::SelectEnum;
+
+#[derive(SelectEnum)]
+enum Speed {}
+
+fn main() {}
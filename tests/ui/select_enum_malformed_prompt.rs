@@ -0,0 +1,10 @@
+use quizzard::SelectEnum;
+
+#[derive(SelectEnum)]
+enum Speed {
+    Slow,
+    #[prompt(100)]
+    Fast,
+}
+
+fn main() {}
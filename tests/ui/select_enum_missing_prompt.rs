@@ -0,0 +1,10 @@
+use quizzard::SelectEnum;
+
+#[derive(SelectEnum)]
+enum Speed {
+    Slow,
+    #[prompt()]
+    Fast,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use quizzard::SelectEnum;
+
+#[derive(SelectEnum)]
+struct Speed {
+    fast: bool,
+}
+
+fn main() {}